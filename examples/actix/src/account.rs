@@ -4,6 +4,7 @@ use serde::Deserialize;
 
 /// Errors for account related operations.
 #[derive(Error, Debug)]
+#[errore(status = 403)]
 pub enum Error {
     #[error("Account with email '{}' is already registered", payload.email)]
     AlreadyRegistered { payload: Json<RegisterRequest> },
@@ -1,6 +1,5 @@
 mod account;
 mod error;
-mod formatter;
 mod subscriber;
 
 use actix_web::web::Json;
@@ -8,23 +7,19 @@ use actix_web::{middleware, post, App, HttpResponse, HttpServer};
 use log::info;
 
 use crate::account::RegisterRequest;
-use crate::formatter::ErrorResponseFormatter;
 use crate::subscriber::ErrorSubscriber;
 
 #[post("/register")]
+#[allow(clippy::result_large_err)]
 async fn register(payload: Json<RegisterRequest>) -> Result<HttpResponse, error::Ec> {
     account::register(payload)?;
-    return Ok(HttpResponse::Ok().into());
+    Ok(HttpResponse::Ok().into())
 }
 
 #[tokio::main]
 async fn main() -> std::io::Result<()> {
     env_logger::builder().format_timestamp(None).init();
 
-    // Use a custom formatter to avoid that any error metadata such as location or name
-    // is included in the error response for the user.
-    errore::formatter!(ErrorResponseFormatter);
-
     // Optionally a user defined subscriber for errors can be used for logging/tracing purposes.
     errore::subscriber!(ErrorSubscriber);
 
@@ -36,7 +31,7 @@ async fn main() -> std::io::Result<()> {
     });
 
     info!("Listen on http://{}", listen);
-    Ok(server.bind(listen)?.run().await?)
+    server.bind(listen)?.run().await
 
     // curl --header "Content-Type: application/json" --request POST --data '{"email":"xyz","password":"xyz"}' http://localhost:8080/register
 }
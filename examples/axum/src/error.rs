@@ -1,35 +1,35 @@
-use axum::{
-    body::Body,
-    http::{header, StatusCode},
-    response::{IntoResponse, Response},
-};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
 use errore::*;
 
 use crate::account;
 
 /// Error type that comprises all errors in this crate.
+///
+/// The `axum` feature implements `IntoResponse` for the context.
+/// The status code is taken from the account errors and the body contains only the error message.
 #[derive(Error, Debug)]
 pub enum AppError {
     #[error(transparent)]
     Account(#[from] account::Ec),
 }
 
-impl Ec {
-    fn status_code(&self) -> StatusCode {
-        if self.has::<account::Error>() {
-            StatusCode::FORBIDDEN
-        } else {
-            StatusCode::INTERNAL_SERVER_ERROR
-        }
-    }
-}
-
-impl IntoResponse for Ec {
-    fn into_response(self) -> Response {
-        Response::builder()
-            .status(self.status_code())
-            .header(header::CONTENT_TYPE, "text/plain")
-            .body(Body::new(self.to_string()))
-            .unwrap()
+/// Renders the error as RFC 7807 problem details document instead of plain text.
+///
+/// In debug builds the document also contains the trace of the error.
+pub fn problem_response(ec: Ec) -> Response {
+    let problem = ProblemDetails::new(&ec);
+    let status = problem
+        .status()
+        .and_then(|status| StatusCode::from_u16(status).ok())
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    match serde_json::to_string(&problem) {
+        Ok(body) => (
+            status,
+            [(header::CONTENT_TYPE, ProblemDetails::CONTENT_TYPE)],
+            body,
+        )
+            .into_response(),
+        Err(_) => ec.into_response(),
     }
 }
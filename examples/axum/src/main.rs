@@ -1,22 +1,30 @@
 mod account;
 mod error;
-mod formatter;
 mod subscriber;
 
-use axum::extract::{MatchedPath, Request};
-use axum::{routing::post, Json, Router};
+use axum::error_handling::HandleErrorLayer;
+use axum::extract::{FromRequest, MatchedPath, Request};
+use axum::response::{IntoResponse, Response};
+use axum::{routing::post_service, Json, Router};
+use errore::middleware::ReportLayer;
+use tower::ServiceBuilder;
 use tower_http::trace::TraceLayer;
 use tracing::{debug_span, info};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 
 use crate::account::RegisterRequest;
-use crate::formatter::ErrorResponseFormatter;
 use crate::subscriber::TracingSubscriber;
 
-async fn register(Json(payload): Json<RegisterRequest>) -> Result<(), error::Ec> {
-    account::register(Json(payload))?;
-    Ok(())
+// The handler is used as service, so its errors are visible to the 'ReportLayer'.
+#[allow(clippy::result_large_err)]
+async fn register(req: Request) -> Result<Response, error::Ec> {
+    let payload = match Json::<RegisterRequest>::from_request(req, &()).await {
+        Ok(payload) => payload,
+        Err(rejection) => return Ok(rejection.into_response()),
+    };
+    account::register(payload)?;
+    Ok(().into_response())
 }
 
 #[tokio::main]
@@ -30,33 +38,40 @@ async fn main() -> std::io::Result<()> {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
-    // Use a custom formatter to avoid that any error metadata such as location or name
-    // is included in the error response for the user.
-    errore::formatter!(ErrorResponseFormatter);
-
     // Optionally a user defined subscriber for errors can be used for logging/tracing purposes.
     errore::subscriber!(TracingSubscriber);
 
-    let app = Router::new().route("/register", post(register)).layer(
-        TraceLayer::new_for_http()
-            // Create our own span for the request and include the matched path. The matched
-            // path is useful for figuring out which handler the request was routed to.
-            .make_span_with(|req: &Request| {
-                let method = req.method();
-                let uri = req.uri();
+    // Errors of the service are reported with the request that caused them
+    // and converted to a problem details response afterwards.
+    let register = ServiceBuilder::new()
+        .layer(HandleErrorLayer::new(|ec: error::Ec| async move {
+            error::problem_response(ec)
+        }))
+        .layer(ReportLayer::new())
+        .service_fn(register);
+
+    let app = Router::new()
+        .route("/register", post_service(register))
+        .layer(
+            TraceLayer::new_for_http()
+                // Create our own span for the request and include the matched path. The matched
+                // path is useful for figuring out which handler the request was routed to.
+                .make_span_with(|req: &Request| {
+                    let method = req.method();
+                    let uri = req.uri();
 
-                // axum automatically adds this extension.
-                let matched_path = req
-                    .extensions()
-                    .get::<MatchedPath>()
-                    .map(|matched_path| matched_path.as_str());
+                    // axum automatically adds this extension.
+                    let matched_path = req
+                        .extensions()
+                        .get::<MatchedPath>()
+                        .map(|matched_path| matched_path.as_str());
 
-                debug_span!("request", %method, %uri, matched_path)
-            })
-            // By default `TraceLayer` will log 5xx responses but we're doing our specific
-            // logging of errors so disable that
-            .on_failure(()),
-    );
+                    debug_span!("request", %method, %uri, matched_path)
+                })
+                // By default `TraceLayer` will log 5xx responses but we're doing our specific
+                // logging of errors so disable that
+                .on_failure(()),
+        );
 
     let listen = "127.0.0.1:8080";
     let listener = tokio::net::TcpListener::bind(listen).await.unwrap();
@@ -1,4 +1,6 @@
-use errore::{subscriber::Subscriber, Extract, Id, TraceContext};
+use errore::{
+    middleware::RequestContext, subscriber::Subscriber, Extension, Extract, Id, TraceContext,
+};
 use tracing::{info, warn};
 
 use crate::account;
@@ -15,6 +17,11 @@ impl Subscriber for TracingSubscriber {
             return;
         }
 
+        // the request is inserted by the 'ReportLayer' if the error was returned by a service
+        if let Some(req) = ctx.extensions().get::<RequestContext>() {
+            info!("{} {} failed", req.method, req.uri);
+        }
+
         // 'ctx.get::<account::Error>()' can also be used if the value is needed
         if ctx.has::<account::Error>() {
             // print a more detailed error report with a backtrace
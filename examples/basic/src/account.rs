@@ -4,17 +4,24 @@ use crate::auth;
 
 /// Errors for account related operations.
 #[derive(Error, Debug)]
+#[errore(kind)]
+#[allow(clippy::large_enum_variant)]
 pub enum Error {
     #[error(transparent)]
     Authentication(#[from] auth::Ec),
     #[error("Submitted captcha '{hash}' is wrong")]
     WrongCaptcha { hash: String },
+    // the session is masked as '***' when the error is displayed
     #[error("Captcha session '{session}' was not found or is expired")]
-    InvalidCaptcha { session: String },
+    InvalidCaptcha {
+        #[redact]
+        session: String,
+    },
 }
 
 // Automatically generated:
 // pub struct Ec(pub Span<Error>)
+// pub enum ErrorKind { Authentication, WrongCaptcha, InvalidCaptcha } (with #[errore(kind)])
 
 pub fn login(email: &str, password: &str) -> Result<(), Ec> {
     auth::verify(email, password)?;
@@ -6,6 +6,7 @@ use errore::prelude::*;
 
 /// Errors for any failed authentication.
 #[derive(Error, Debug)]
+#[errore(kind)]
 pub enum Error {
     #[error("Invalid email or password")]
     ReadPassword(#[from] std::io::Error),
@@ -15,6 +16,7 @@ pub enum Error {
 
 // Automatically generated:
 // pub struct Ec(pub Span<Error>)
+// pub enum ErrorKind { ReadPassword, InvalidCredentials } (with #[errore(kind)])
 
 fn read_password(email: &str) -> Result<String, Ec> {
     Ok(fs::read_to_string(PathBuf::from(email))?)
@@ -3,6 +3,7 @@ mod auth;
 
 use errore::prelude::*;
 
+#[allow(clippy::single_match)]
 fn main() {
     env_logger::builder().format_timestamp(None).init();
 
@@ -36,6 +37,15 @@ fn main() {
             _ => {}
         }
 
+        // error comparison with 'kind()':
+        // useful for storing or comparing errors without keeping them alive
+        if ec.kind() == account::ErrorKind::Authentication {
+            let origin = ec.trace().first().id;
+            if origin == auth::ErrorKind::ReadPassword.id() {
+                println!("\nError comparison with 'kind()':\n{:?}", ec.kind());
+            }
+        }
+
         // error extraction with 'get()':
         // useful for deeply nested errors
         if let Some(auth_error) = ec.get::<auth::Error>() {
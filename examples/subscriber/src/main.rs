@@ -22,7 +22,7 @@ impl Subscriber for MySubscriber {
         println!("[{}] on_end(): {}", ctx.last().target, ctx.last().name);
 
         // access and print user data
-        let target = ctx.last().target;
+        let target = ctx.last().target.clone();
         let mut ext = ctx.extensions_mut();
         let data = ext.get_mut::<MyData>().expect("MyData must exist");
 
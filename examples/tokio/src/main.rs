@@ -7,6 +7,7 @@ pub mod codec {
 
     /// Codec related errors.
     #[derive(Error, Debug)]
+    #[allow(clippy::large_enum_variant)]
     pub enum Error {
         #[error("Corrupted data at position {pos}")]
         Corrupted { pos: usize },
@@ -1,5 +1,7 @@
-use errore::{subscriber::Subscriber, Extract, Id, TraceContext};
-use tracing::{error, info, trace, warn};
+use std::fmt::{self, Write};
+
+use errore::{subscriber::Subscriber, FieldVisitor, Id, Severity, TraceContext};
+use tracing::{error, info, warn};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 pub mod parser {
@@ -10,6 +12,7 @@ pub mod parser {
         #[error("Tag is missing:\n'{line}'\n\t")]
         NoTag { line: String },
         #[error("No header was found")]
+        #[errore(severity = "warn")]
         NoHeader,
     }
 
@@ -35,7 +38,20 @@ impl Subscriber for TracingSubscriber {
         }
 
         // Log a simple error.
-        info!(error.r#type = err.name, "{}", err);
+        info!(error.r#type = &*err.name, "{}", err);
+    }
+}
+
+/// Collects the fields of an error as `key=value` pairs.
+#[derive(Default)]
+struct Fields(String);
+
+impl FieldVisitor for Fields {
+    fn record_debug(&mut self, name: &str, value: &dyn fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        let _ = write!(self.0, "{}={:?}", name, value);
     }
 }
 
@@ -56,23 +72,25 @@ impl Subscriber for TracingDebugSubscriber {
             return;
         }
 
-        // Optionally handle specific error types.
-        // If no error instance is needed, 'ctx.has()' can be used.
-        if let Some(err) = ctx.get::<parser::Error>() {
-            use parser::*;
-            match &*err {
-                Error::NoTag { line } => {
-                    error!(error.r#type = rec.name, line = line, "{}", rec);
-                }
-                Error::NoHeader => {
-                    warn!(error.r#type = rec.name, "{}", rec);
-                }
+        // Log the fields of any error without knowing its type.
+        // Specific error types can still be accessed with 'ctx.get::<parser::Error>()'.
+        let mut fields = Fields::default();
+        rec.visit_fields(&mut fields);
+
+        // Choose the log level by the severity of the error.
+        match rec.severity() {
+            Severity::Warn => warn!(error.r#type = &*rec.name, fields = fields.0, "{}", rec),
+            Severity::Error => error!(error.r#type = &*rec.name, fields = fields.0, "{}", rec),
+            Severity::Fatal => {
+                error!(
+                    error.r#type = &*rec.name,
+                    fields = fields.0,
+                    fatal = true,
+                    "{}",
+                    rec
+                )
             }
-            return;
         }
-
-        // Log a simple error.
-        trace!(error.r#type = rec.name, "{}", rec);
     }
 
     fn on_end(&self, ctx: &mut TraceContext) {
@@ -83,12 +101,13 @@ impl Subscriber for TracingDebugSubscriber {
             return;
         }
 
-        // Log a detailed report.
+        // Log a detailed report with the highest severity of the trace.
         info!(
-            error.r#type = rec.name,
+            error.r#type = &*rec.name,
+            error.severity = ctx.severity().as_str(),
             exception.message = rec.to_string(),
             exception.stacktrace = ctx.to_string(),
-            exception.r#type = rec.name,
+            exception.r#type = &*rec.name,
         );
     }
 }
@@ -20,6 +20,7 @@ pub enum DeriveType {
 
 #[allow(dead_code)]
 pub struct Struct<'a> {
+    pub original: &'a DeriveInput,
     pub derive: DeriveType,
     pub attrs: Attrs<'a>,
     pub ident: Ident,
@@ -74,7 +75,9 @@ impl<'a> Struct<'a> {
         if let Some(display) = &mut attrs.display {
             display.expand_shorthand(&fields);
         }
+        attrs.expand_diagnostics(&fields);
         Ok(Struct {
+            original: node,
             derive,
             attrs,
             ident: node.ident.clone(),
@@ -103,6 +106,14 @@ impl<'a> Enum<'a> {
                 } else if variant.attrs.transparent.is_none() {
                     variant.attrs.transparent = attrs.transparent;
                 }
+                // The diagnostics of the enum are used as default for its variants.
+                if let diagnostic @ None = &mut variant.attrs.help {
+                    diagnostic.clone_from(&attrs.help);
+                }
+                if let diagnostic @ None = &mut variant.attrs.note {
+                    diagnostic.clone_from(&attrs.note);
+                }
+                variant.attrs.expand_diagnostics(&variant.fields);
                 Ok(variant)
             })
             .collect::<Result<_>>()?;
@@ -5,8 +5,8 @@ use quote::{format_ident, quote, ToTokens};
 use syn::parse::discouraged::Speculative;
 use syn::parse::ParseStream;
 use syn::{
-    braced, bracketed, parenthesized, token, Attribute, Error, Ident, Index, Lifetime, LitFloat,
-    LitInt, LitStr, Meta, Result, Token,
+    braced, bracketed, parenthesized, token, Attribute, Error, ExprPath, Ident, Index, Lifetime,
+    LitFloat, LitInt, LitStr, Meta, Result, Token, Type,
 };
 
 #[derive(Default)]
@@ -14,14 +14,30 @@ pub struct Attrs<'a> {
     pub display: Option<Display<'a>>,
     pub source: Option<&'a Attribute>,
     pub from: Option<&'a Attribute>,
+    pub conversion: Option<Conversion>,
     pub transparent: Option<Transparent<'a>>,
     pub doc: Option<&'a Attribute>,
+    pub code: Option<Code<'a>>,
+    pub severity: Option<Severity<'a>>,
+    pub classification: Option<Classification<'a>>,
+    pub status: Option<Status<'a>>,
+    pub grpc: Option<Grpc<'a>>,
+    pub help: Option<Display<'a>>,
+    pub note: Option<Display<'a>>,
+    pub backtrace: Option<&'a Attribute>,
+    pub redact: Option<&'a Attribute>,
+    pub context: Option<TypeName<'a>>,
+    pub kind: Option<Kind<'a>>,
+    pub name: Option<Label<'a>>,
+    pub target: Option<Label<'a>>,
 }
 
 #[derive(Clone)]
 pub struct Display<'a> {
     pub original: &'a Attribute,
     pub fmt: LitStr,
+    /// The format string as written by the user, before shorthands were expanded.
+    pub template: String,
     pub args: TokenStream,
     pub requires_fmt_machinery: bool,
     pub has_bonus_display: bool,
@@ -32,6 +48,7 @@ pub struct Display<'a> {
 impl<'a> Display<'a> {
     pub fn recursing(&self) -> Option<TokenStream> {
         for arg in &self.arg_tokens {
+            #[allow(clippy::match_single_binding)]
             match arg {
                 // expressions with 'self' included (for e.g. in 'test_nested_display') doesn't work
                 // need to check whether a function/expression is used
@@ -56,6 +73,73 @@ pub struct Transparent<'a> {
     pub span: Span,
 }
 
+/// A user defined conversion with `#[from(via = Type, with = path)]`.
+#[derive(Clone)]
+pub struct Conversion {
+    /// The source type of the `From` implementation.
+    pub via: Option<Type>,
+    /// The function that converts the source type to the field type.
+    pub with: Option<ExprPath>,
+}
+
+#[derive(Clone)]
+pub struct Code<'a> {
+    pub original: &'a Attribute,
+    pub value: String,
+    pub span: Span,
+}
+
+#[derive(Clone)]
+pub struct Severity<'a> {
+    pub original: &'a Attribute,
+    pub value: String,
+    pub span: Span,
+}
+
+/// Either `transient` or `permanent` in `#[errore(...)]`.
+#[derive(Clone)]
+pub struct Classification<'a> {
+    pub original: &'a Attribute,
+    pub value: String,
+    pub span: Span,
+}
+
+/// The HTTP status code of `#[errore(status = ...)]`.
+#[derive(Clone)]
+pub struct Status<'a> {
+    pub original: &'a Attribute,
+    pub value: u16,
+    pub span: Span,
+}
+
+/// The gRPC status code of `#[errore(grpc = "...")]`.
+#[derive(Clone)]
+pub struct Grpc<'a> {
+    pub original: &'a Attribute,
+    pub value: String,
+    pub span: Span,
+}
+
+#[derive(Clone)]
+pub struct TypeName<'a> {
+    pub original: &'a Attribute,
+    pub ident: Ident,
+}
+
+/// The fieldless kind enum of `#[errore(kind)]`, optionally named with `#[errore(kind = Name)]`.
+#[derive(Clone)]
+pub struct Kind<'a> {
+    pub original: &'a Attribute,
+    pub ident: Option<Ident>,
+}
+
+/// A string that overrides a generated name with `#[errore(name = "...", target = "...")]`.
+#[derive(Clone)]
+pub struct Label<'a> {
+    pub original: &'a Attribute,
+    pub value: String,
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
 pub enum Trait {
     Debug,
@@ -78,7 +162,7 @@ pub enum Argument {
     Punct(Punct),
 }
 
-pub fn get(input: &[Attribute]) -> Result<Attrs> {
+pub fn get(input: &[Attribute]) -> Result<Attrs<'_>> {
     let mut attrs = Attrs::default();
 
     for attr in input {
@@ -91,6 +175,8 @@ pub fn get(input: &[Attribute]) -> Result<Attrs> {
             parse_display_attribute(&mut attrs, attr, "error")?;
         } else if attr.path().is_ident("display") {
             parse_display_attribute(&mut attrs, attr, "display")?;
+        } else if attr.path().is_ident("errore") {
+            parse_errore_attribute(&mut attrs, attr)?;
         } else if attr.path().is_ident("source") {
             attr.meta.require_path_only()?;
             if attrs.source.is_some() {
@@ -100,6 +186,9 @@ pub fn get(input: &[Attribute]) -> Result<Attrs> {
         } else if attr.path().is_ident("from") {
             match attr.meta {
                 Meta::Path(_) => {}
+                Meta::List(_) if is_conversion(attr) => {
+                    attrs.conversion = Some(parse_conversion(attr)?);
+                }
                 Meta::List(_) | Meta::NameValue(_) => {
                     // Assume this is meant for derive_more crate or something.
                     continue;
@@ -109,6 +198,18 @@ pub fn get(input: &[Attribute]) -> Result<Attrs> {
                 return Err(Error::new_spanned(attr, "duplicate #[from] attribute"));
             }
             attrs.from = Some(attr);
+        } else if attr.path().is_ident("backtrace") {
+            attr.meta.require_path_only()?;
+            if attrs.backtrace.is_some() {
+                return Err(Error::new_spanned(attr, "duplicate #[backtrace] attribute"));
+            }
+            attrs.backtrace = Some(attr);
+        } else if attr.path().is_ident("redact") {
+            attr.meta.require_path_only()?;
+            if attrs.redact.is_some() {
+                return Err(Error::new_spanned(attr, "duplicate #[redact] attribute"));
+            }
+            attrs.redact = Some(attr);
         } else if attr.path().is_ident("doc") {
             attrs.doc = Some(attr);
         }
@@ -155,6 +256,7 @@ fn parse_display_attribute<'a>(
 
         let display = Display {
             original: attr,
+            template: fmt.value(),
             fmt,
             args,
             requires_fmt_machinery,
@@ -173,6 +275,285 @@ fn parse_display_attribute<'a>(
     })
 }
 
+fn is_conversion(attr: &Attribute) -> bool {
+    attr.parse_args_with(|input: ParseStream| {
+        let key = input.fork().parse::<Ident>().ok();
+        input.parse::<TokenStream>()?;
+        Ok(key.is_some_and(|key| key == "via" || key == "with"))
+    })
+    .unwrap_or(false)
+}
+
+fn parse_conversion(attr: &Attribute) -> Result<Conversion> {
+    attr.parse_args_with(|input: ParseStream| {
+        let mut conversion = Conversion {
+            via: None,
+            with: None,
+        };
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            if key == "via" {
+                if conversion.via.is_some() {
+                    return Err(Error::new_spanned(&key, "duplicate via key"));
+                }
+                conversion.via = Some(input.parse()?);
+            } else if key == "with" {
+                if conversion.with.is_some() {
+                    return Err(Error::new_spanned(&key, "duplicate with key"));
+                }
+                conversion.with = Some(input.parse()?);
+            } else {
+                return Err(Error::new_spanned(
+                    &key,
+                    format!("unknown key `{}` in #[from(...)] attribute", key),
+                ));
+            }
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        Ok(conversion)
+    })
+}
+
+fn parse_errore_attribute<'a>(attrs: &mut Attrs<'a>, attr: &'a Attribute) -> Result<()> {
+    attr.parse_args_with(|input: ParseStream| {
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key == "kind" {
+                if attrs.kind.is_some() {
+                    return Err(Error::new_spanned(&key, "duplicate kind key"));
+                }
+                let ident = if input.parse::<Option<Token![=]>>()?.is_some() {
+                    Some(input.parse()?)
+                } else {
+                    None
+                };
+                attrs.kind = Some(Kind {
+                    original: attr,
+                    ident,
+                });
+                if input.is_empty() {
+                    break;
+                }
+                input.parse::<Token![,]>()?;
+                continue;
+            }
+            if FLAGS.contains(&key.to_string().as_str()) {
+                if attrs.classification.is_some() {
+                    return Err(Error::new_spanned(
+                        &key,
+                        "duplicate classification, expected either transient or permanent",
+                    ));
+                }
+                attrs.classification = Some(Classification {
+                    original: attr,
+                    value: key.to_string(),
+                    span: key.span(),
+                });
+                if input.is_empty() {
+                    break;
+                }
+                input.parse::<Token![,]>()?;
+                continue;
+            }
+            input.parse::<Token![=]>()?;
+            if key == "name" || key == "target" {
+                let label = if key == "name" {
+                    &mut attrs.name
+                } else {
+                    &mut attrs.target
+                };
+                if label.is_some() {
+                    return Err(Error::new_spanned(&key, format!("duplicate {} key", key)));
+                }
+                let value: LitStr = input.parse()?;
+                if value.value().is_empty() {
+                    return Err(Error::new_spanned(
+                        &value,
+                        format!("{} must not be empty", key),
+                    ));
+                }
+                *label = Some(Label {
+                    original: attr,
+                    value: value.value(),
+                });
+                if input.is_empty() {
+                    break;
+                }
+                input.parse::<Token![,]>()?;
+                continue;
+            }
+            if METADATA_KEYS.contains(&key.to_string().as_str()) {
+                parse_metadata_key(attrs, attr, &key, input)?;
+                if input.is_empty() {
+                    break;
+                }
+                input.parse::<Token![,]>()?;
+                continue;
+            }
+            let name = if key == "context" {
+                &mut attrs.context
+            } else {
+                return Err(Error::new_spanned(
+                    &key,
+                    format!("unknown key `{}` in #[errore(...)] attribute", key),
+                ));
+            };
+            if name.is_some() {
+                return Err(Error::new_spanned(&key, format!("duplicate {} key", key)));
+            }
+            *name = Some(TypeName {
+                original: attr,
+                ident: input.parse()?,
+            });
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        Ok(())
+    })
+}
+
+/// Keys of the `#[errore(...)]` attribute that describe the error itself.
+const METADATA_KEYS: &[&str] = &["code", "severity", "status", "grpc", "help", "note"];
+
+/// Keys of the `#[errore(...)]` attribute without a value.
+const FLAGS: &[&str] = &["transient", "permanent"];
+
+/// Values that are accepted by the `severity` key.
+const SEVERITIES: &[&str] = &["warn", "error", "fatal"];
+
+/// Values that are accepted by the `grpc` key, `OK` is not an error.
+const GRPC_CODES: &[&str] = &[
+    "CANCELLED",
+    "UNKNOWN",
+    "INVALID_ARGUMENT",
+    "DEADLINE_EXCEEDED",
+    "NOT_FOUND",
+    "ALREADY_EXISTS",
+    "PERMISSION_DENIED",
+    "RESOURCE_EXHAUSTED",
+    "FAILED_PRECONDITION",
+    "ABORTED",
+    "OUT_OF_RANGE",
+    "UNIMPLEMENTED",
+    "INTERNAL",
+    "UNAVAILABLE",
+    "DATA_LOSS",
+    "UNAUTHENTICATED",
+];
+
+/// Parses the value of a key in [`METADATA_KEYS`], the `=` is already consumed.
+fn parse_metadata_key<'a>(
+    attrs: &mut Attrs<'a>,
+    attr: &'a Attribute,
+    key: &Ident,
+    input: ParseStream,
+) -> Result<()> {
+    if key == "code" {
+        let (value, span) = if input.peek(LitStr) {
+            let lit: LitStr = input.parse()?;
+            (lit.value(), lit.span())
+        } else if input.peek(LitInt) {
+            let lit: LitInt = input.parse()?;
+            (lit.base10_digits().to_string(), lit.span())
+        } else {
+            return Err(input.error("expected a string or integer literal"));
+        };
+        if value.is_empty() {
+            return Err(Error::new(span, "error code must not be empty"));
+        }
+        if attrs.code.is_some() {
+            return Err(Error::new_spanned(key, "duplicate code key"));
+        }
+        attrs.code = Some(Code {
+            original: attr,
+            value,
+            span,
+        });
+    } else if key == "severity" {
+        let lit: LitStr = input.parse()?;
+        let value = lit.value();
+        if !SEVERITIES.contains(&value.as_str()) {
+            return Err(Error::new(
+                lit.span(),
+                "expected one of \"warn\", \"error\" or \"fatal\"",
+            ));
+        }
+        if attrs.severity.is_some() {
+            return Err(Error::new_spanned(key, "duplicate severity key"));
+        }
+        attrs.severity = Some(Severity {
+            original: attr,
+            value,
+            span: lit.span(),
+        });
+    } else if key == "status" {
+        let lit: LitInt = input.parse()?;
+        let value = match lit.base10_parse::<u16>() {
+            Ok(value @ 100..=999) => value,
+            _ => {
+                return Err(Error::new(
+                    lit.span(),
+                    "expected a HTTP status code between 100 and 999",
+                ))
+            }
+        };
+        if attrs.status.is_some() {
+            return Err(Error::new_spanned(key, "duplicate status key"));
+        }
+        attrs.status = Some(Status {
+            original: attr,
+            value,
+            span: lit.span(),
+        });
+    } else if key == "grpc" {
+        let lit: LitStr = input.parse()?;
+        let value = lit.value();
+        if !GRPC_CODES.contains(&value.as_str()) {
+            return Err(Error::new(
+                lit.span(),
+                "expected a gRPC status code like \"NOT_FOUND\" or \"UNAVAILABLE\"",
+            ));
+        }
+        if attrs.grpc.is_some() {
+            return Err(Error::new_spanned(key, "duplicate grpc key"));
+        }
+        attrs.grpc = Some(Grpc {
+            original: attr,
+            value,
+            span: lit.span(),
+        });
+    } else if key == "help" || key == "note" {
+        let fmt: LitStr = input.parse()?;
+        let diagnostic = if key == "help" {
+            &mut attrs.help
+        } else {
+            &mut attrs.note
+        };
+        if diagnostic.is_some() {
+            return Err(Error::new_spanned(key, format!("duplicate {} key", key)));
+        }
+        *diagnostic = Some(Display {
+            original: attr,
+            template: fmt.value(),
+            fmt,
+            args: TokenStream::new(),
+            requires_fmt_machinery: false,
+            has_bonus_display: false,
+            implied_bounds: Set::new(),
+            arg_tokens: Vec::new(),
+        });
+    } else {
+        unreachable!("`{}` is not a metadata key", key);
+    }
+    Ok(())
+}
+
 fn parse_token_expr(
     input: ParseStream,
     mut begin_expr: bool,
@@ -63,7 +63,7 @@ fn impl_enum(input: Enum) -> TokenStream {
         v.attrs
             .display
             .as_ref()
-            .map_or(false, |display| display.has_bonus_display)
+            .is_some_and(|display| display.has_bonus_display)
     });
     let use_as_display = use_as_display(has_bonus_display);
     let void_deref = if input.variants.is_empty() {
@@ -81,7 +81,7 @@ fn impl_enum(input: Enum) -> TokenStream {
                 display.to_token_stream()
             }
             None => {
-                if variant.fields.len() > 0 {
+                if !variant.fields.is_empty() {
                     let only_field = match &variant.fields[0].member {
                         Member::Named(ident) => ident.clone(),
                         Member::Unnamed(index) => format_ident!("_{}", index),
@@ -137,7 +137,7 @@ pub fn impl_struct_display_body(
     input: &Struct,
     display_implied_bounds: &mut Set<(usize, Trait)>,
 ) -> Option<TokenStream> {
-    return if input.attrs.transparent.is_some() {
+    if input.attrs.transparent.is_some() {
         let only_field = &input.fields[0].member;
         display_implied_bounds.insert((0, Trait::Display));
         Some(quote! {
@@ -155,7 +155,7 @@ pub fn impl_struct_display_body(
         })
     } else {
         None
-    };
+    }
 }
 
 pub fn impl_struct_display(
@@ -174,7 +174,7 @@ pub fn impl_struct_display(
         }
     }
     let display_where_clause = display_inferred_bounds.augment_where_clause(input.generics);
-    return display_body.as_ref().map(|body| {
+    display_body.as_ref().map(|body| {
         quote! {
             #[allow(unused_qualifications)]
             #[automatically_derived]
@@ -185,7 +185,7 @@ pub fn impl_struct_display(
                 }
             }
         }
-    });
+    })
 }
 
 fn impl_struct(input: Struct) -> TokenStream {
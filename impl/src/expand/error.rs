@@ -3,16 +3,19 @@ use std::collections::BTreeSet as Set;
 
 use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
-    parse_quote, DeriveInput, Generics, Ident, ImplGenerics, Member, Result, Token, TypeGenerics,
-    Visibility, WhereClause,
+    parse_quote, Attribute, DeriveInput, Generics, Ident, ImplGenerics, Member, Result, Token,
+    TypeGenerics, Visibility, WhereClause,
 };
 
 use crate::ast::{DeriveType, Enum, Field, Input, Struct, Variant};
-use crate::attr::{Attrs, Trait};
+use crate::attr::{self, Attrs, Trait};
 use crate::expand::display;
 use crate::generics::InferredBounds;
 use crate::span::MemberSpan;
-use crate::util::{fields_pat, from_initializer, type_is_option, unoptional_type, use_as_display};
+use crate::util::{
+    doc_string, field_record, fields_pat, from_conversion, from_initializer, nested_probe,
+    snake_case, type_is_option, type_is_vec, type_string, unoptional_type, use_as_display,
+};
 
 pub fn derive(input: &DeriveInput) -> TokenStream {
     match try_expand(input) {
@@ -42,6 +45,10 @@ fn fallback(input: &DeriveInput, error: syn::Error) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     let error = error.to_compile_error();
+    let context = match attr::get(&input.attrs) {
+        Ok(attrs) => context_ident(&attrs),
+        Err(_) => format_ident!("Ec"),
+    };
     let static_lifetime = if input.generics.lifetimes().count() > 0 {
         Some(quote! {<'static>})
     } else {
@@ -68,12 +75,10 @@ fn fallback(input: &DeriveInput, error: syn::Error) -> TokenStream {
             }
         }
 
-        extern crate alloc;
-
         #[allow(unused_qualifications)]
         #[automatically_derived]
         #[derive(Debug)]
-        #vis struct Ec #ty_generics (pub errore::span::Span<#ty #ty_generics>) #where_clause;
+        #vis struct #context #ty_generics (pub errore::span::Span<#ty #ty_generics>) #where_clause;
 
         #[allow(unused_qualifications)]
         #[automatically_derived]
@@ -101,11 +106,39 @@ fn fallback(input: &DeriveInput, error: syn::Error) -> TokenStream {
             fn is_transparent(&self) -> bool {
                 ::core::unreachable!()
             }
+
+            fn code(&self) -> ::core::option::Option<&'static str> {
+                ::core::unreachable!()
+            }
+
+            fn severity(&self) -> errore::Severity {
+                ::core::unreachable!()
+            }
+
+            fn classification(&self) -> errore::Classification {
+                ::core::unreachable!()
+            }
+
+            fn status(&self) -> ::core::option::Option<u16> {
+                ::core::unreachable!()
+            }
+
+            fn grpc_code(&self) -> ::core::option::Option<&'static str> {
+                ::core::unreachable!()
+            }
+
+            fn help(&self) -> ::core::option::Option<errore::__private::alloc::string::String> {
+                ::core::unreachable!()
+            }
+
+            fn note(&self) -> ::core::option::Option<errore::__private::alloc::string::String> {
+                ::core::unreachable!()
+            }
         }
 
         #[allow(unused_qualifications)]
         #[automatically_derived]
-        impl #impl_generics ::core::convert::From<#ty #static_lifetime> for Ec #ty_generics #where_clause {
+        impl #impl_generics ::core::convert::From<#ty #static_lifetime> for #context #ty_generics #where_clause {
             fn from(value: #ty #static_lifetime) -> Self {
                 ::core::unreachable!()
             }
@@ -130,7 +163,7 @@ fn get_var(var_type: StaticVariable, ty: &Ident, ident: Option<&Ident>) -> Token
         "__{:?}{}{}",
         var_type,
         ty,
-        ident.map(|f| f.to_string()).unwrap_or(String::new())
+        ident.map(|f| f.to_string()).unwrap_or_default()
     ))
     .unwrap()
 }
@@ -150,7 +183,38 @@ fn access_static_var(
     }}
 }
 
-fn impl_static_var<'a>(ty: &'a Ident, mut field: Option<&'a Ident>) -> TokenStream {
+/// Resolves the names of `#[errore(name = "...", target = "...")]` for a struct or an enum variant.
+///
+/// The name of an enum is used as prefix for the names of its variants.
+fn static_var_labels(
+    attrs: &Attrs,
+    variant: Option<(&Ident, &Attrs)>,
+) -> (Option<String>, Option<String>) {
+    let Some((ident, variant_attrs)) = variant else {
+        return (
+            attrs.name.as_ref().map(|name| name.value.clone()),
+            attrs.target.as_ref().map(|target| target.value.clone()),
+        );
+    };
+    let name = match (&variant_attrs.name, &attrs.name) {
+        (Some(name), _) => Some(name.value.clone()),
+        (None, Some(prefix)) => Some(format!("{}::{}", prefix.value, ident)),
+        (None, None) => None,
+    };
+    let target = variant_attrs
+        .target
+        .as_ref()
+        .or(attrs.target.as_ref())
+        .map(|target| target.value.clone());
+    (name, target)
+}
+
+fn impl_static_var<'a>(
+    ty: &'a Ident,
+    mut field: Option<&'a Ident>,
+    name: Option<String>,
+    target: Option<String>,
+) -> TokenStream {
     // Miri doesn't handle link sections! -> https://github.com/rust-lang/miri/issues/450
     // Moreover some platforms are not supported by crates that utilize link sections.
     // For example 'riscv32imac-unknown-none-elf' fails to build with 'rust-ctor'.
@@ -172,66 +236,223 @@ fn impl_static_var<'a>(ty: &'a Ident, mut field: Option<&'a Ident>) -> TokenStre
         field = Some(ty);
     }
 
-    let block_name = quote! {{
-        let mut module_path = module_path!();
-        let mut id = alloc::string::String::with_capacity(module_path.len() + 10);
-        alloc::format!(
-            "{}::{}::{}",
-            env!("CARGO_PKG_NAME").replace("-", "_"),
-            module_path.rsplit("::").next().unwrap(),
-            stringify!(#field)
-        )
-    }};
+    let block_name = match &name {
+        Some(name) => quote! {{
+            errore::__private::alloc::string::String::from(#name)
+        }},
+        None => quote! {{
+            let mut module_path = module_path!();
+            let mut id = errore::__private::alloc::string::String::with_capacity(module_path.len() + 10);
+            errore::__private::alloc::format!(
+                "{}::{}::{}",
+                env!("CARGO_PKG_NAME").replace("-", "_"),
+                module_path.rsplit("::").next().unwrap(),
+                stringify!(#field)
+            )
+        }},
+    };
 
-    let block_id = quote! {{
-        errore::Id::from(
-            errore::__private::fnv1a_hash_64(
-                concat!(
-                    env!("CARGO_PKG_NAME"),
-                    module_path!(),
-                    stringify!(#ty),
-                    stringify!(#field)
-                ).as_bytes()
+    let block_target = match &target {
+        Some(target) => quote! {{
+            errore::__private::alloc::string::String::from(#target)
+        }},
+        None => quote! {{
+            let mut module_path = module_path!();
+            errore::__private::alloc::format!(
+                "{}",
+                module_path.split("::").next().unwrap(),
             )
-        )
-    }};
+        }},
+    };
 
-    let block_target = quote! {{
-        let mut module_path = module_path!();
-        alloc::format!(
-            "{}",
-            module_path.split("::").next().unwrap(),
-        )
-    }};
+    // Overridden names are hashed with the target,
+    // so the identifier stays the same if the error is moved to another module.
+    let block_id = if name.is_some() || target.is_some() {
+        quote! {{
+            let name = #block_name;
+            let target = #block_target;
+            errore::Id::from(
+                errore::__private::fnv1a_hash_64(
+                    errore::__private::alloc::format!("{}::{}", target, name).as_bytes()
+                )
+            )
+        }}
+    } else {
+        quote! {{
+            errore::Id::from(
+                errore::__private::fnv1a_hash_64(
+                    concat!(
+                        env!("CARGO_PKG_NAME"),
+                        module_path!(),
+                        stringify!(#ty),
+                        stringify!(#field)
+                    ).as_bytes()
+                )
+            )
+        }}
+    };
 
     let block_target_id = quote! {{
-        let mut module_path = module_path!();
-        errore::Id::from(
-            errore::__private::fnv1a_hash_64(
-                alloc::format!(
-                    "{}",
-                    module_path.split("::").next().unwrap(),
-                ).as_bytes()
-            )
-        )
+        let target = #block_target;
+        errore::Id::from(errore::__private::fnv1a_hash_64(target.as_bytes()))
     }};
 
     quote! {
-        errore::__private::impl_static_var!(#ctor_name, #lazy_name, alloc::string::String, #block_name);
+        errore::__private::impl_static_var!(#ctor_name, #lazy_name, errore::__private::alloc::string::String, #block_name);
         errore::__private::impl_static_var!(#ctor_id, #lazy_id, errore::Id, #block_id);
-        errore::__private::impl_static_var!(#ctor_target, #lazy_target, alloc::string::String, #block_target);
+        errore::__private::impl_static_var!(#ctor_target, #lazy_target, errore::__private::alloc::string::String, #block_target);
         errore::__private::impl_static_var!(#ctor_target_id, #lazy_target_id, errore::Id, #block_target_id);
     }
 }
 
-fn is_error_context(token: &TokenStream) -> bool {
-    // It is possible to also use the feature 'error_generic_member_access'
-    // instead of using a keyword here.
-    // However, the api is not matured enough and doesn't provide methods to access and
-    // mutate the error itself.
-    // Moreover the relevant fields need to be wrapped in a 'Arc<Mutex<T>>' type to be useful.
-    let token_str = token.to_string();
-    token_str.ends_with(":: Ec") || token_str.contains(":: Ec <")
+fn impl_descriptor(
+    ty: &Ident,
+    variant: Option<&Ident>,
+    attrs: &Attrs,
+    severity_attr: Option<&attr::Severity>,
+    from_field: Option<&Field>,
+    docs: &[Attribute],
+) -> TokenStream {
+    let var_name = access_static_var(
+        StaticVariable::CtorName,
+        StaticVariable::LazyName,
+        ty,
+        variant,
+    );
+    let var_id = access_static_var(StaticVariable::CtorId, StaticVariable::LazyId, ty, variant);
+    let var_target = access_static_var(
+        StaticVariable::CtorTarget,
+        StaticVariable::LazyTarget,
+        ty,
+        variant,
+    );
+    let var_target_id = access_static_var(
+        StaticVariable::CtorTargetId,
+        StaticVariable::LazyTargetId,
+        ty,
+        variant,
+    );
+    let display = option_str(attrs.display.as_ref().map(|display| &display.template));
+    let doc = doc_string(docs);
+    let from = option_str(
+        from_field
+            .map(|field| type_string(&field.from_type()))
+            .as_ref(),
+    );
+    let is_transparent = attrs.transparent.is_some();
+    let code = option_str(attrs.code.as_ref().map(|code| &code.value));
+    let severity = severity(severity_attr);
+
+    // descriptors are only collected with link sections, see 'impl_static_var'
+    quote! {
+        errore::__private::impl_descriptor!(errore::registry::Descriptor::new(
+            || #var_name,
+            || #var_id,
+            || #var_target,
+            || #var_target_id,
+            #display,
+            #doc,
+            #from,
+            #is_transparent,
+            #code,
+            #severity,
+        ));
+    }
+}
+
+fn option_str(value: Option<&String>) -> TokenStream {
+    match value {
+        Some(value) => quote!(::core::option::Option::Some(#value)),
+        None => quote!(::core::option::Option::None),
+    }
+}
+
+fn grpc_code(value: Option<&attr::Grpc>) -> TokenStream {
+    match value {
+        Some(grpc) => {
+            let value = &grpc.value;
+            quote_spanned!(grpc.span=> ::core::option::Option::Some(#value))
+        }
+        None => quote!(::core::option::Option::None),
+    }
+}
+
+fn severity(value: Option<&attr::Severity>) -> TokenStream {
+    match value {
+        Some(severity) => {
+            let variant = match severity.value.as_str() {
+                "warn" => quote!(Warn),
+                "fatal" => quote!(Fatal),
+                _ => quote!(Error),
+            };
+            quote_spanned!(severity.span=> errore::Severity::#variant)
+        }
+        None => quote!(errore::Severity::Error),
+    }
+}
+
+/// Forwards the severity of the field bound to `transparent`,
+/// if it implements `Metadata`. Otherwise `value` is used as fallback.
+fn transparent_severity(value: Option<&attr::Severity>) -> TokenStream {
+    let default = severity(value);
+    quote! {{
+        use errore::__private::{MetadataSeverity as _, UntracedSeverity as _};
+        (&&errore::__private::SeverityProbe(transparent)).probe_severity(#default)
+    }}
+}
+
+fn classification(value: Option<&attr::Classification>) -> TokenStream {
+    match value {
+        Some(classification) => {
+            let variant = match classification.value.as_str() {
+                "transient" => quote!(Transient),
+                _ => quote!(Permanent),
+            };
+            quote_spanned!(classification.span=> errore::Classification::#variant)
+        }
+        None => quote!(errore::Classification::Unclassified),
+    }
+}
+
+fn status(value: Option<&attr::Status>) -> TokenStream {
+    match value {
+        Some(status) => {
+            let value = status.value;
+            quote_spanned!(status.span=> ::core::option::Option::Some(#value))
+        }
+        None => quote!(::core::option::Option::None),
+    }
+}
+
+fn diagnostic(value: Option<&attr::Display>) -> TokenStream {
+    match value {
+        Some(display) => {
+            let fmt = &display.fmt;
+            let args = &display.args;
+            if display.requires_fmt_machinery {
+                quote!(::core::option::Option::Some(
+                    errore::__private::alloc::format!(#fmt #args)
+                ))
+            } else {
+                quote!(::core::option::Option::Some(errore::__private::alloc::string::String::from(#fmt)))
+            }
+        }
+        None => quote!(::core::option::Option::None),
+    }
+}
+
+fn kind_ident(ty: &Ident, attrs: &Attrs) -> Ident {
+    match attrs.kind.as_ref().and_then(|kind| kind.ident.as_ref()) {
+        Some(ident) => ident.clone(),
+        None => format_ident!("{}Kind", ty),
+    }
+}
+
+fn context_ident(attrs: &Attrs) -> Ident {
+    match &attrs.context {
+        Some(context) => context.ident.clone(),
+        None => format_ident!("Ec"),
+    }
 }
 
 fn impl_from<'a>(
@@ -242,7 +463,8 @@ fn impl_from<'a>(
     from_field: &'a Field,
     variant: Option<&Ident>,
 ) -> TokenStream {
-    let from = unoptional_type(from_field.ty);
+    let from = from_field.from_type();
+    let conversion = from_conversion(from_field);
     let body = from_initializer(from_field);
     let error = match variant {
         Some(v) => quote! { #ty::#v #body },
@@ -263,6 +485,7 @@ fn impl_from<'a>(
                     module_path!(),
                     #ty_str
                 );
+                #conversion
                 #error
             }
         }
@@ -275,12 +498,28 @@ fn impl_enum(input: Enum) -> Result<TokenStream> {
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let mut error_inferred_bounds = InferredBounds::new();
 
-    let lazy_vars_arms = input
-        .variants
-        .iter()
-        .map(|variant| impl_static_var(ty, Some(&variant.ident)));
+    let lazy_vars_arms = input.variants.iter().map(|variant| {
+        let (name, target) =
+            static_var_labels(&input.attrs, Some((&variant.ident, &variant.attrs)));
+        impl_static_var(ty, Some(&variant.ident), name, target)
+    });
+    let descriptors = input.variants.iter().map(|variant| {
+        impl_descriptor(
+            ty,
+            Some(&variant.ident),
+            &variant.attrs,
+            variant
+                .attrs
+                .severity
+                .as_ref()
+                .or(input.attrs.severity.as_ref()),
+            variant.from_field(),
+            &variant.original.attrs,
+        )
+    });
     let lazy_vars = quote! {
         #(#lazy_vars_arms)*
+        #(#descriptors)*
     };
 
     for variant in &input.variants {
@@ -312,6 +551,9 @@ fn impl_enum(input: Enum) -> Result<TokenStream> {
                 }
                 let asref = if type_is_option(source_field.ty) {
                     Some(quote_spanned!(source.member_span()=> .as_ref()?))
+                } else if type_is_vec(source_field.ty) {
+                    // The first nested error is used as source.
+                    Some(quote_spanned!(source.member_span()=> .first()?))
                 } else {
                     None
                 };
@@ -347,7 +589,7 @@ fn impl_enum(input: Enum) -> Result<TokenStream> {
             v.attrs
                 .display
                 .as_ref()
-                .map_or(false, |display| display.has_bonus_display)
+                .is_some_and(|display| display.has_bonus_display)
         });
         let use_as_display = use_as_display(has_bonus_display);
         let void_deref = if input.variants.is_empty() {
@@ -478,6 +720,136 @@ fn impl_enum(input: Enum) -> Result<TokenStream> {
             }
         });
 
+        let code_arms = input.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let code = option_str(variant.attrs.code.as_ref().map(|code| &code.value));
+            quote! {
+                #ty::#ident {..} => #code,
+            }
+        });
+
+        let nested_arms = input.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let pat = fields_pat(&variant.fields);
+            let probes = variant.fields.iter().map(|field| {
+                let var = match &field.member {
+                    Member::Named(ident) => ident.clone(),
+                    Member::Unnamed(index) => format_ident!("_{}", index),
+                };
+                nested_probe(field.ty, quote!(#var))
+            });
+            quote! {
+                #ty::#ident #pat => {
+                    #(#probes)*
+                }
+            }
+        });
+
+        // The severity of the enum is used as default for its variants,
+        // transparent variants forward the severity of their field instead.
+        let severity_arms = input.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            if variant.attrs.transparent.is_some() && variant.attrs.severity.is_none() {
+                let member = &variant.fields[0].member;
+                let severity = transparent_severity(input.attrs.severity.as_ref());
+                return quote! {
+                    #ty::#ident {#member: transparent} => #severity,
+                };
+            }
+            let severity = severity(
+                variant
+                    .attrs
+                    .severity
+                    .as_ref()
+                    .or(input.attrs.severity.as_ref()),
+            );
+            quote! {
+                #ty::#ident {..} => #severity,
+            }
+        });
+
+        // Like the severity, the classification of the enum is used as default for its variants.
+        let classification_arms = input.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let classification = classification(
+                variant
+                    .attrs
+                    .classification
+                    .as_ref()
+                    .or(input.attrs.classification.as_ref()),
+            );
+            quote! {
+                #ty::#ident {..} => #classification,
+            }
+        });
+
+        let status_arms = input.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let status = status(
+                variant
+                    .attrs
+                    .status
+                    .as_ref()
+                    .or(input.attrs.status.as_ref()),
+            );
+            quote! {
+                #ty::#ident {..} => #status,
+            }
+        });
+
+        let grpc_arms = input.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let grpc_code = grpc_code(variant.attrs.grpc.as_ref().or(input.attrs.grpc.as_ref()));
+            quote! {
+                #ty::#ident {..} => #grpc_code,
+            }
+        });
+
+        let has_bonus_display = input.variants.iter().any(|v| {
+            [&v.attrs.help, &v.attrs.note]
+                .into_iter()
+                .flatten()
+                .any(|diagnostic| diagnostic.has_bonus_display)
+        });
+        let use_as_display = use_as_display(has_bonus_display);
+        let help_arms = input.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let pat = fields_pat(&variant.fields);
+            let help = diagnostic(variant.attrs.help.as_ref());
+            quote! {
+                #ty::#ident #pat => #help,
+            }
+        });
+        let note_arms = input.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let pat = fields_pat(&variant.fields);
+            let note = diagnostic(variant.attrs.note.as_ref());
+            quote! {
+                #ty::#ident #pat => #note,
+            }
+        });
+
+        let field_arms = input.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let pat = fields_pat(&variant.fields);
+            let records = variant.fields.iter().map(field_record);
+            quote! {
+                #ty::#ident #pat => {
+                    #(#records)*
+                }
+            }
+        });
+
+        // The backtrace attribute of the enum is used for all of its variants.
+        let backtrace_arms = input.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let has_backtrace =
+                variant.attrs.backtrace.is_some() || input.attrs.backtrace.is_some();
+            quote! {
+                #ty::#ident {..} => #has_backtrace,
+            }
+        });
+
         Some(quote! {
             #[allow(unused_qualifications)]
             #[automatically_derived]
@@ -516,10 +888,81 @@ fn impl_enum(input: Enum) -> Result<TokenStream> {
                         #(#transparent_arms)*
                     }
                 }
+
+                fn code(&self) -> ::core::option::Option<&'static str> {
+                    match self {
+                        #(#code_arms)*
+                    }
+                }
+
+                fn severity(&self) -> errore::Severity {
+                    match self {
+                        #(#severity_arms)*
+                    }
+                }
+
+                fn classification(&self) -> errore::Classification {
+                    match self {
+                        #(#classification_arms)*
+                    }
+                }
+
+                fn status(&self) -> ::core::option::Option<u16> {
+                    match self {
+                        #(#status_arms)*
+                    }
+                }
+
+                fn grpc_code(&self) -> ::core::option::Option<&'static str> {
+                    match self {
+                        #(#grpc_arms)*
+                    }
+                }
+
+                fn help(&self) -> ::core::option::Option<errore::__private::alloc::string::String> {
+                    #use_as_display
+                    #[allow(unused_variables, deprecated, clippy::used_underscore_binding)]
+                    match self {
+                        #(#help_arms)*
+                    }
+                }
+
+                fn note(&self) -> ::core::option::Option<errore::__private::alloc::string::String> {
+                    #use_as_display
+                    #[allow(unused_variables, deprecated, clippy::used_underscore_binding)]
+                    match self {
+                        #(#note_arms)*
+                    }
+                }
+
+                fn visit_fields(&self, visitor: &mut dyn errore::FieldVisitor) {
+                    use errore::__private::{DebugField as _, OpaqueField as _};
+                    #[allow(unused_variables, deprecated, clippy::used_underscore_binding, clippy::unnecessary_cast)]
+                    match self {
+                        #(#field_arms)*
+                    }
+                }
+
+                fn visit_nested(&self, visitor: &mut dyn FnMut(&errore::TraceContext)) {
+                    use errore::__private::{MetadataNested as _, TraceableNested as _, UntracedNested as _};
+                    #[allow(unused_variables, deprecated)]
+                    match self {
+                        #(#nested_arms)*
+                    }
+                }
+
+                fn has_backtrace(&self) -> bool {
+                    match self {
+                        #(#backtrace_arms)*
+                    }
+                }
             }
         })
     };
 
+    // The kind enum is opt-in, otherwise it could collide with an imported `ErrorKind`.
+    let kind_impl = input.attrs.kind.as_ref().map(|_| impl_kind(&input));
+
     let impl_extractable = quote! {
         #[allow(unused_qualifications)]
         #[automatically_derived]
@@ -545,7 +988,7 @@ fn impl_enum(input: Enum) -> Result<TokenStream> {
     let from_fields = input
         .variants
         .iter()
-        .filter_map(|variant| Some(variant.from_field()?))
+        .filter_map(|variant| variant.from_field())
         .collect::<Vec<&Field>>();
     let impl_error_context = impl_error_context(
         &input.ident,
@@ -562,6 +1005,7 @@ fn impl_enum(input: Enum) -> Result<TokenStream> {
         #lazy_vars
         #display_impl
         #metadata_impl
+        #kind_impl
         #(#from_impls)*
         #impl_extractable
         #impl_error
@@ -569,13 +1013,129 @@ fn impl_enum(input: Enum) -> Result<TokenStream> {
     })
 }
 
+fn impl_kind(input: &Enum) -> TokenStream {
+    let ty = &input.ident;
+    let vis = &input.vis;
+    let kind = kind_ident(ty, &input.attrs);
+    let context = context_ident(&input.attrs);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let doc = if input.attrs.doc.is_some() {
+        let doc_str = format!("Fieldless kind of [`{}`](enum.{}.html).", ty, ty);
+        Some(quote! {
+            #[doc = #doc_str]
+        })
+    } else {
+        None
+    };
+
+    // Forward the documentation of every variant.
+    let variants = input.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let docs = variant
+            .original
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"));
+        quote! {
+            #(#docs)*
+            #ident
+        }
+    });
+
+    let kind_arms = input.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        quote! {
+            #ty::#ident {..} => #kind::#ident,
+        }
+    });
+
+    let id_arms = input.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let var = access_static_var(
+            StaticVariable::CtorId,
+            StaticVariable::LazyId,
+            ty,
+            Some(ident),
+        );
+        quote! {
+            #kind::#ident => *#var,
+        }
+    });
+
+    // Empty enums can't be matched by reference.
+    let void_deref = if input.variants.is_empty() {
+        Some(quote!(*))
+    } else {
+        None
+    };
+
+    quote! {
+        #doc
+        #[allow(unused_qualifications)]
+        #[automatically_derived]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #vis enum #kind {
+            #(#variants,)*
+        }
+
+        #[allow(unused_qualifications)]
+        #[automatically_derived]
+        impl #kind {
+            /// Returns the identifier of the error kind.
+            ///
+            /// The identifier is equal to [`Metadata::id`](errore::Metadata::id)
+            /// and [`TraceRecord::id`](errore::TraceRecord::id).
+            pub fn id(&self) -> errore::Id {
+                match #void_deref self {
+                    #(#id_arms)*
+                }
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        #[automatically_derived]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            /// Returns the fieldless kind of the error.
+            pub fn kind(&self) -> #kind {
+                match #void_deref self {
+                    #(#kind_arms)*
+                }
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        #[automatically_derived]
+        impl #impl_generics #context #ty_generics #where_clause {
+            /// Returns the fieldless kind of the inherited error.
+            #[inline]
+            pub fn kind(&self) -> #kind {
+                self.error().kind()
+            }
+        }
+    }
+}
+
 fn impl_struct(input: Struct) -> Result<TokenStream> {
     let ty = &input.ident;
     let mut error = Option::<TokenStream>::None;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let mut error_inferred_bounds = InferredBounds::new();
 
-    let lazy_vars = impl_static_var(ty, None);
+    let (name, target) = static_var_labels(&input.attrs, None);
+    let lazy_vars = impl_static_var(ty, None, name, target);
+    let descriptor = impl_descriptor(
+        ty,
+        None,
+        &input.attrs,
+        input.attrs.severity.as_ref(),
+        input.from_field(),
+        &input.original.attrs,
+    );
+    let lazy_vars = quote! {
+        #lazy_vars
+        #descriptor
+    };
 
     if let Some(display) = &input.attrs.display {
         error = display.recursing();
@@ -598,6 +1158,9 @@ fn impl_struct(input: Struct) -> Result<TokenStream> {
         }
         let asref = if type_is_option(source_field.ty) {
             Some(quote_spanned!(source.member_span()=> .as_ref()?))
+        } else if type_is_vec(source_field.ty) {
+            // The first nested error is used as source.
+            Some(quote_spanned!(source.member_span()=> .first()?))
         } else {
             None
         };
@@ -652,6 +1215,42 @@ fn impl_struct(input: Struct) -> Result<TokenStream> {
             None,
         );
         let is_transparent = input.attrs.transparent.is_some();
+        let code = option_str(input.attrs.code.as_ref().map(|code| &code.value));
+        let severity = match &input.attrs.transparent {
+            Some(_) if input.attrs.severity.is_none() => {
+                let member = &input.fields[0].member;
+                let severity = transparent_severity(None);
+                quote! {
+                    let transparent = &self.#member;
+                    #severity
+                }
+            }
+            _ => severity(input.attrs.severity.as_ref()),
+        };
+        let classification = classification(input.attrs.classification.as_ref());
+        let status = status(input.attrs.status.as_ref());
+        let grpc_code = grpc_code(input.attrs.grpc.as_ref());
+        let has_backtrace = input.attrs.backtrace.is_some();
+        let pat = fields_pat(&input.fields);
+        let [help, note] = [&input.attrs.help, &input.attrs.note].map(|value| match value {
+            Some(display) => {
+                let use_as_display = use_as_display(display.has_bonus_display);
+                let diagnostic = diagnostic(Some(display));
+                quote! {
+                    #use_as_display
+                    #[allow(unused_variables, deprecated, clippy::used_underscore_binding)]
+                    let Self #pat = self;
+                    #diagnostic
+                }
+            }
+            None => diagnostic(None),
+        });
+        let field_pat = fields_pat(&input.fields);
+        let field_records = input.fields.iter().map(field_record);
+        let nested_probes = input.fields.iter().map(|field| {
+            let member = &field.member;
+            nested_probe(field.ty, quote!(&self.#member))
+        });
         quote! {
             #[allow(unused_qualifications)]
             #[automatically_derived]
@@ -685,6 +1284,57 @@ fn impl_struct(input: Struct) -> Result<TokenStream> {
                 fn is_transparent(&self) -> bool {
                     #is_transparent
                 }
+
+                #[inline]
+                fn code(&self) -> ::core::option::Option<&'static str> {
+                    #code
+                }
+
+                #[inline]
+                fn severity(&self) -> errore::Severity {
+                    #severity
+                }
+
+                #[inline]
+                fn classification(&self) -> errore::Classification {
+                    #classification
+                }
+
+                #[inline]
+                fn status(&self) -> ::core::option::Option<u16> {
+                    #status
+                }
+
+                #[inline]
+                fn grpc_code(&self) -> ::core::option::Option<&'static str> {
+                    #grpc_code
+                }
+
+                fn help(&self) -> ::core::option::Option<errore::__private::alloc::string::String> {
+                    #help
+                }
+
+                fn note(&self) -> ::core::option::Option<errore::__private::alloc::string::String> {
+                    #note
+                }
+
+                #[allow(unused_variables, deprecated, clippy::used_underscore_binding, clippy::unnecessary_cast)]
+                fn visit_fields(&self, visitor: &mut dyn errore::FieldVisitor) {
+                    use errore::__private::{DebugField as _, OpaqueField as _};
+                    let Self #field_pat = self;
+                    #(#field_records)*
+                }
+
+                #[allow(deprecated)]
+                fn visit_nested(&self, visitor: &mut dyn FnMut(&errore::TraceContext)) {
+                    use errore::__private::{MetadataNested as _, TraceableNested as _, UntracedNested as _};
+                    #(#nested_probes)*
+                }
+
+                #[inline]
+                fn has_backtrace(&self) -> bool {
+                    #has_backtrace
+                }
             }
         }
     };
@@ -746,6 +1396,131 @@ fn impl_struct(input: Struct) -> Result<TokenStream> {
     })
 }
 
+/// Names of methods, that are already implemented for the error context
+/// or would shadow a trait method with the same name.
+///
+/// Keep in sync with the traits that are implemented in `impl_error_context`.
+const RESERVED_METHODS: &[&str] = &[
+    // inherent methods
+    "new",
+    "error",
+    "kind",
+    "into_parts",
+    "into_inner",
+    "attachments",
+    // errore::Metadata
+    "name",
+    "id",
+    "target",
+    "target_id",
+    "display",
+    "is_transparent",
+    "code",
+    "severity",
+    "classification",
+    "status",
+    "grpc_code",
+    "help",
+    "note",
+    "visit_fields",
+    "visit_nested",
+    "has_backtrace",
+    // errore::Traceable
+    "trace",
+    "trace_ref",
+    "trace_mut",
+    "take_trace",
+    "inner",
+    "insert",
+    // errore::Extract and errore::Classify
+    "get",
+    "has",
+    "take",
+    "classify",
+    // core traits
+    "fmt",
+    "source",
+    "from",
+    "into_iter",
+    // axum and actix-web responses
+    "into_response",
+    "status_code",
+    "error_response",
+];
+
+/// Generates a constructor and a predicate for every variant of an enum,
+/// for e.g. `Ec::wrong_captcha(hash)` and `ec.is_wrong_captcha()`.
+fn impl_variant_methods(ty: &Ident, generics: &Generics, variants: &[Variant]) -> TokenStream {
+    let methods = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let name = snake_case(ident);
+        let variant_doc = format!("[`{}::{}`](enum.{}.html#variant.{})", ty, ident, ty, ident);
+        let deprecated = variant
+            .original
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("deprecated"));
+
+        // Errors with borrowed data can't be constructed with a static lifetime.
+        let constructor = if generics.lifetimes().next().is_none()
+            && !RESERVED_METHODS.contains(&name.as_str())
+        {
+            let constructor = match syn::parse_str::<Ident>(&name) {
+                Ok(constructor) => constructor,
+                Err(_) => Ident::new_raw(&name, ident.span()),
+            };
+            let params = variant.fields.iter().map(|field| {
+                let ty = field.ty;
+                match &field.member {
+                    Member::Named(ident) => quote!(#ident: #ty),
+                    Member::Unnamed(index) => {
+                        let ident = format_ident!("_{}", index);
+                        quote!(#ident: #ty)
+                    }
+                }
+            });
+            let pat = fields_pat(&variant.fields);
+            let doc_str = format!("Creates a new context for {}.", variant_doc);
+            Some(quote! {
+                #[doc = #doc_str]
+                #(#deprecated)*
+                #[allow(deprecated)]
+                #[allow(clippy::too_many_arguments)]
+                #[track_caller]
+                pub fn #constructor(#(#params),*) -> Self {
+                    Self::new(#ty::#ident #pat)
+                }
+            })
+        } else {
+            None
+        };
+
+        let predicate = format_ident!("is_{}", name);
+        let predicate = if RESERVED_METHODS.contains(&predicate.to_string().as_str()) {
+            None
+        } else {
+            let doc_str = format!("Returns `true` if the inherited error is {}.", variant_doc);
+            Some(quote! {
+                #[doc = #doc_str]
+                #[allow(deprecated)]
+                #[inline]
+                pub fn #predicate(&self) -> bool {
+                    ::core::matches!(self.error(), #ty::#ident {..})
+                }
+            })
+        };
+
+        quote! {
+            #constructor
+            #predicate
+        }
+    });
+
+    quote! {
+        #(#methods)*
+    }
+}
+
 fn impl_error_context(
     ty: &Ident,
     vis: &Visibility,
@@ -754,6 +1529,8 @@ fn impl_error_context(
     variants: Option<&Vec<Variant>>,
     from_fields: Vec<&Field>,
 ) -> TokenStream {
+    let context = context_ident(attrs);
+    let context_str = context.to_string();
     let vis = vis.to_token_stream();
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let mut iter_generics = generics.clone();
@@ -770,7 +1547,7 @@ fn impl_error_context(
     let doc = if attrs.doc.is_some() {
         let mut from_doc = String::with_capacity(128);
         for from_field in &from_fields {
-            let from = unoptional_type(from_field.ty).to_string().replace(" ", "");
+            let from = from_field.from_type().to_string().replace(" ", "");
             from_doc.push_str(&format!("- [{}]\n\n", from));
         }
         if !from_doc.is_empty() {
@@ -793,60 +1570,85 @@ fn impl_error_context(
         None
     };
 
+    let variant_methods = variants.map(|variants| impl_variant_methods(ty, generics, variants));
+
+    // The features of errore are not visible to this crate,
+    // so the implementations are only expanded by errore if the respective feature is enabled.
+    let axum_impl = quote! {
+        errore::__private::impl_axum_response! {
+            #[allow(unused_qualifications)]
+            #[automatically_derived]
+            impl #impl_generics errore::__private::axum::response::IntoResponse for #context #static_lifetime #where_clause {
+                fn into_response(self) -> errore::__private::axum::response::Response {
+                    errore::__private::axum_response(self.0.status_code(), self.0.format_response())
+                }
+            }
+        }
+    };
+
+    let actix_impl = quote! {
+        errore::__private::impl_actix_response! {
+            #[allow(unused_qualifications)]
+            #[automatically_derived]
+            impl #impl_generics errore::__private::actix_web::ResponseError for #context #static_lifetime #where_clause {
+                fn status_code(&self) -> errore::__private::actix_web::http::StatusCode {
+                    errore::__private::actix_status(self.0.status_code())
+                }
+
+                fn error_response(&self) -> errore::__private::actix_web::HttpResponse {
+                    errore::__private::actix_response(self.0.status_code(), self.0.format_response())
+                }
+            }
+        }
+    };
+
+    let tonic_impl = quote! {
+        errore::__private::impl_grpc_status! {
+            #[allow(unused_qualifications)]
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::From<#context #static_lifetime> for errore::__private::tonic::Status #where_clause {
+                fn from(value: #context #static_lifetime) -> Self {
+                    value.0.grpc_status()
+                }
+            }
+        }
+    };
+
     let from_impls = from_fields.iter().map(|from_field| {
-        let from = unoptional_type(from_field.ty);
+        let from = from_field.from_type();
         let from_str = from.to_string();
 
-        // conversion from error context
-        if is_error_context(&from) {
-            Some(quote! {
-                #[allow(unused_qualifications)]
-                #[automatically_derived]
-                impl ::core::convert::From<#from> for Ec {
-                    #[track_caller]
-                    fn from(mut value: #from) -> Self {
-                        errore::dlog!(
-                            "From<{}> for {}::Ec",
-                            #from_str,
-                            module_path!()
-                        );
-                        let ctx = value.take_trace();
-                        Self(errore::span::Span::new(ctx, #ty::from(value)))
-                    }
-                }
-            })
-        } else {
-            Some(quote! {
-                #[allow(unused_qualifications)]
-                #[automatically_derived]
-                impl ::core::convert::From<#from> for Ec {
-                    #[track_caller]
-                    fn from(value: #from) -> Self {
-                        errore::dlog!(
-                            "From<{}> for {}::Ec",
-                            #from_str,
-                            module_path!()
-                        );
-                        let ctx = None;
-                        Self(errore::span::Span::new(ctx, #ty::from(value)))
-                    }
+        // Takes the trace of an error context, regardless of the name of its type.
+        quote! {
+            #[allow(unused_qualifications)]
+            #[automatically_derived]
+            impl ::core::convert::From<#from> for #context {
+                #[track_caller]
+                fn from(mut value: #from) -> Self {
+                    use errore::__private::{TraceableProbe as _, UntraceableProbe as _};
+                    errore::dlog!(
+                        "From<{}> for {}::{}",
+                        #from_str,
+                        module_path!(),
+                        #context_str
+                    );
+                    let ctx = (&mut errore::__private::TraceProbe(&mut value)).probe_trace();
+                    Self(errore::span::Span::new(ctx, #ty::from(value)))
                 }
-            })
+            }
         }
     });
 
     quote! {
-        extern crate alloc;
-
         #doc
         #[allow(unused_qualifications)]
         #[automatically_derived]
         #[derive(Debug)]
-        #vis struct Ec #ty_generics (#[doc(hidden)] pub errore::span::Span<#ty #ty_generics>) #where_clause;
+        #vis struct #context #ty_generics (#[doc(hidden)] pub errore::span::Span<#ty #ty_generics>) #where_clause;
 
         #[allow(unused_qualifications)]
         #[automatically_derived]
-        impl #impl_generics Ec #ty_generics #where_clause {
+        impl #impl_generics #context #ty_generics #where_clause {
             #[track_caller]
             pub fn new(kind: #ty #static_lifetime) -> Self {
                 Self(errore::span::Span::new(None, kind))
@@ -857,11 +1659,37 @@ fn impl_error_context(
             pub fn error(&self) -> &#ty #ty_generics {
                 self.0.inner.as_ref()
             }
+
+            /// Returns the values of type `A` attached with `Result::attach()`, in propagation order.
+            #[inline]
+            pub fn attachments<__A: ::core::any::Any>(&self) -> impl ::core::iter::Iterator<Item = &__A> {
+                self.0.ctx.iter().flat_map(errore::TraceContext::attachments::<__A>)
+            }
+
+            /// Moves the inherited error and the trace context out of the context.
+            ///
+            /// The context is returned unchanged if the error is still shared,
+            /// for e.g. by a [`errore::Downcasted`], or if the trace was already moved to another context.
+            /// The records of the trace context no longer refer to the moved error.
+            #[inline]
+            pub fn into_parts(self) -> ::core::result::Result<(#ty #ty_generics, errore::TraceContext), Self> {
+                self.0.into_parts().map_err(Self)
+            }
+
+            /// Moves the inherited error out of the context and drops the trace.
+            ///
+            /// See [`into_parts()`](Self::into_parts) for details.
+            #[inline]
+            pub fn into_inner(self) -> ::core::result::Result<#ty #ty_generics, Self> {
+                self.into_parts().map(|(inner, _)| inner)
+            }
+
+            #variant_methods
         }
 
         #[allow(unused_qualifications)]
         #[automatically_derived]
-        impl #impl_generics ::core::fmt::Display for Ec #ty_generics #where_clause {
+        impl #impl_generics ::core::fmt::Display for #context #ty_generics #where_clause {
             #[inline]
             fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                 self.0.fmt(f)
@@ -870,7 +1698,7 @@ fn impl_error_context(
 
         #[allow(unused_qualifications)]
         #[automatically_derived]
-        impl #impl_generics ::core::error::Error for Ec #static_lifetime #where_clause {
+        impl #impl_generics ::core::error::Error for #context #static_lifetime #where_clause {
             #[inline]
             fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
                 Some(&self.0 as &dyn ::core::error::Error)
@@ -879,7 +1707,7 @@ fn impl_error_context(
 
         #[allow(unused_qualifications)]
         #[automatically_derived]
-        impl #iter_impl_generics IntoIterator for &'iter Ec #static_lifetime #iter_where_clause {
+        impl #iter_impl_generics IntoIterator for &'iter #context #static_lifetime #iter_where_clause {
             type Item = &'iter errore::TraceRecord;
 
             type IntoIter = errore::__private::TraceRecordIterator<'iter>;
@@ -893,7 +1721,7 @@ fn impl_error_context(
         // Forwards metadata implementation to the inner enum or struct error.
         #[allow(unused_qualifications)]
         #[automatically_derived]
-        impl #impl_generics errore::Metadata for Ec #static_lifetime #where_clause {
+        impl #impl_generics errore::Metadata for #context #static_lifetime #where_clause {
             #[inline]
             fn name(&self) -> &'static str {
                 self.0.inner.name()
@@ -923,11 +1751,61 @@ fn impl_error_context(
             fn is_transparent(&self) -> bool {
                 self.0.inner.is_transparent()
             }
+
+            #[inline]
+            fn code(&self) -> ::core::option::Option<&'static str> {
+                self.0.inner.code()
+            }
+
+            #[inline]
+            fn severity(&self) -> errore::Severity {
+                self.0.inner.severity()
+            }
+
+            #[inline]
+            fn classification(&self) -> errore::Classification {
+                self.0.inner.classification()
+            }
+
+            #[inline]
+            fn status(&self) -> ::core::option::Option<u16> {
+                self.0.inner.status()
+            }
+
+            #[inline]
+            fn grpc_code(&self) -> ::core::option::Option<&'static str> {
+                self.0.inner.grpc_code()
+            }
+
+            #[inline]
+            fn help(&self) -> ::core::option::Option<errore::__private::alloc::string::String> {
+                self.0.inner.help()
+            }
+
+            #[inline]
+            fn note(&self) -> ::core::option::Option<errore::__private::alloc::string::String> {
+                self.0.inner.note()
+            }
+
+            #[inline]
+            fn visit_fields(&self, visitor: &mut dyn errore::FieldVisitor) {
+                self.0.inner.visit_fields(visitor)
+            }
+
+            #[inline]
+            fn visit_nested(&self, visitor: &mut dyn FnMut(&errore::TraceContext)) {
+                self.0.inner.visit_nested(visitor)
+            }
+
+            #[inline]
+            fn has_backtrace(&self) -> bool {
+                self.0.inner.has_backtrace()
+            }
         }
 
         #[allow(unused_qualifications)]
         #[automatically_derived]
-        impl #impl_generics errore::Extract for Ec #ty_generics #where_clause {
+        impl #impl_generics errore::Extract for #context #ty_generics #where_clause {
             #[inline]
             fn get<'a, E>(&'a self) -> Option<errore::Downcasted<'a, E>>
             where
@@ -947,7 +1825,7 @@ fn impl_error_context(
 
         #[allow(unused_qualifications)]
         #[automatically_derived]
-        impl #impl_generics errore::Traceable for Ec #ty_generics #where_clause {
+        impl #impl_generics errore::Traceable for #context #ty_generics #where_clause {
             #[inline]
             fn trace(&self) -> &errore::TraceContext {
                 self.0.ctx.as_ref().expect("Trace should be available in 'Traceable::trace'")
@@ -958,13 +1836,18 @@ fn impl_error_context(
                 self.0.ctx.as_ref()
             }
 
+            #[inline]
+            fn trace_mut(&mut self) -> Option<&mut errore::TraceContext> {
+                self.0.ctx.as_mut()
+            }
+
             #[inline]
             fn take_trace(&mut self) -> Option<errore::TraceContext> {
                 self.0.ctx.take()
             }
 
             #[inline]
-            fn inner(&self) -> alloc::sync::Arc<dyn ::core::error::Error + ::core::marker::Send + ::core::marker::Sync> {
+            fn inner(&self) -> errore::__private::alloc::sync::Arc<dyn errore::__private::ErrorMetadata> {
                 return self.0.inner.clone();
             }
 
@@ -989,19 +1872,26 @@ fn impl_error_context(
         #[allow(unused_qualifications)]
         #[automatically_derived]
         // Used to convert an enum field or struct to context.
-        impl #impl_generics ::core::convert::From<#ty #static_lifetime> for Ec #ty_generics #where_clause {
+        impl #impl_generics ::core::convert::From<#ty #static_lifetime> for #context #ty_generics #where_clause {
             #[track_caller]
             fn from(value: #ty #static_lifetime) -> Self {
                 errore::dlog!(
-                    "From<{}::{}> for {}::Ec",
+                    "From<{}::{}> for {}::{}",
                     module_path!(),
                     #ty_str,
-                    module_path!()
+                    module_path!(),
+                    #context_str
                 );
                 Self(errore::span::Span::new(None, value))
             }
         }
 
         #(#from_impls)*
+
+        #axum_impl
+
+        #actix_impl
+
+        #tonic_impl
     }
 }
@@ -1,5 +1,6 @@
-use proc_macro2::TokenTree;
+use proc_macro2::{TokenStream, TokenTree};
 use std::collections::{BTreeSet as Set, HashMap as Map};
+use std::mem;
 
 use quote::{format_ident, quote_spanned};
 use syn::ext::IdentExt;
@@ -7,7 +8,7 @@ use syn::parse::{ParseStream, Parser};
 use syn::{Ident, Index, LitStr, Member, Result, Token};
 
 use crate::ast::Field;
-use crate::attr::{Display, Trait};
+use crate::attr::{Attrs, Display, Trait};
 
 impl Display<'_> {
     // Transform `"error {var}"` to `"error {}", var`.
@@ -18,6 +19,14 @@ impl Display<'_> {
         for (i, field) in fields.iter().enumerate() {
             member_index.insert(&field.member, i);
         }
+        let redacted_members = fields
+            .iter()
+            .filter(|field| field.attrs.redact.is_some())
+            .map(|field| &field.member)
+            .collect::<Vec<_>>();
+        if !redacted_members.is_empty() {
+            self.args = redact_explicit_args(self.args.clone(), &redacted_members);
+        }
 
         let span = self.fmt.span();
         let fmt = self.fmt.value();
@@ -69,7 +78,10 @@ impl Display<'_> {
                 }
                 _ => continue,
             };
-            if let Some(&field) = member_index.get(&member) {
+            let redacted = member_index
+                .get(&member)
+                .is_some_and(|&field| fields[field].attrs.redact.is_some());
+            if let (Some(&field), false) = (member_index.get(&member), redacted) {
                 let end_spec = match read.find('}') {
                     Some(end_spec) => end_spec,
                     None => return,
@@ -101,6 +113,12 @@ impl Display<'_> {
                 formatvar = format_ident!("field_{}", formatvar);
             }
             out += &formatvar.to_string();
+            if redacted {
+                // The format spec is dropped, since it applies to the value of the field.
+                if let Some(end_spec) = read.find('}') {
+                    read = &read[end_spec..];
+                }
+            }
             if !named_args.insert(formatvar.clone()) {
                 // Already specified in the format argument list.
                 continue;
@@ -108,6 +126,11 @@ impl Display<'_> {
             if !has_trailing_comma {
                 args.extend(quote_spanned!(span=> ,));
             }
+            if redacted {
+                args.extend(quote_spanned!(span=> #formatvar = errore::__private::Redacted));
+                has_trailing_comma = false;
+                continue;
+            }
             args.extend(quote_spanned!(span=> #formatvar = #local));
             if read.starts_with('}') && member_index.contains_key(&member) {
                 has_bonus_display = true;
@@ -124,6 +147,15 @@ impl Display<'_> {
     }
 }
 
+impl Attrs<'_> {
+    // Transform the `help` and `note` keys like the format string of the error.
+    pub fn expand_diagnostics(&mut self, fields: &[Field]) {
+        for diagnostic in [&mut self.help, &mut self.note].into_iter().flatten() {
+            diagnostic.expand_shorthand(fields);
+        }
+    }
+}
+
 fn explicit_named_args(input: ParseStream) -> Result<Set<Ident>> {
     let mut named_args = Set::new();
 
@@ -141,6 +173,64 @@ fn explicit_named_args(input: ParseStream) -> Result<Set<Ident>> {
     Ok(named_args)
 }
 
+// Replace explicit format arguments that pass a redacted field directly,
+// like `email = self.email` or `email`, with the placeholder.
+fn redact_explicit_args(args: TokenStream, redacted: &[&Member]) -> TokenStream {
+    let mut out = TokenStream::new();
+    let mut arg = Vec::new();
+    for token in args {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                out.extend(redact_arg(mem::take(&mut arg), redacted));
+                out.extend([token]);
+            }
+            _ => arg.push(token),
+        }
+    }
+    out.extend(redact_arg(arg, redacted));
+    out
+}
+
+fn redact_arg(arg: Vec<TokenTree>, redacted: &[&Member]) -> Vec<TokenTree> {
+    let start = match arg.as_slice() {
+        [TokenTree::Ident(_), TokenTree::Punct(eq), ..] if eq.as_char() == '=' => 2,
+        _ => 0,
+    };
+    let mut value = &arg[start..];
+    if let [TokenTree::Punct(amp), rest @ ..] = value {
+        if amp.as_char() == '&' {
+            value = rest;
+        }
+    }
+    let member = match value {
+        [TokenTree::Ident(this), TokenTree::Punct(dot), TokenTree::Ident(ident)]
+            if this == "self" && dot.as_char() == '.' =>
+        {
+            Member::Named(ident.clone())
+        }
+        [TokenTree::Ident(this), TokenTree::Punct(dot), TokenTree::Literal(lit)]
+            if this == "self" && dot.as_char() == '.' =>
+        {
+            match lit.to_string().parse::<u32>() {
+                Ok(index) => Member::Unnamed(Index {
+                    index,
+                    span: lit.span(),
+                }),
+                Err(_) => return arg,
+            }
+        }
+        [TokenTree::Ident(ident)] => Member::Named(ident.clone()),
+        _ => return arg,
+    };
+    if !redacted.contains(&&member) {
+        return arg;
+    }
+    let span = arg[start].span();
+    let mut out = arg[..start].to_vec();
+    out.extend(quote_spanned!(span=> errore::__private::Redacted));
+    out
+}
+
 fn take_int(read: &mut &str) -> String {
     let mut int = String::new();
     for (i, ch) in read.char_indices() {
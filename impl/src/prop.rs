@@ -1,8 +1,10 @@
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
 use syn::Member;
 
 use crate::ast::{Enum, Field, Struct, Variant};
 use crate::span::MemberSpan;
+use crate::util::unoptional_type;
 
 impl Enum<'_> {
     pub(crate) fn has_source(&self) -> bool {
@@ -26,26 +28,34 @@ impl Enum<'_> {
 }
 
 impl Struct<'_> {
-    pub(crate) fn from_field(&self) -> Option<&Field> {
+    pub(crate) fn from_field(&self) -> Option<&Field<'_>> {
         from_field(&self.fields)
     }
 
-    pub(crate) fn source_field(&self) -> Option<&Field> {
+    pub(crate) fn source_field(&self) -> Option<&Field<'_>> {
         source_field(&self.fields)
     }
 }
 
 impl Variant<'_> {
-    pub(crate) fn from_field(&self) -> Option<&Field> {
+    pub(crate) fn from_field(&self) -> Option<&Field<'_>> {
         from_field(&self.fields)
     }
 
-    pub(crate) fn source_field(&self) -> Option<&Field> {
+    pub(crate) fn source_field(&self) -> Option<&Field<'_>> {
         source_field(&self.fields)
     }
 }
 
 impl Field<'_> {
+    /// Returns the type that is converted with the `From` implementation.
+    pub(crate) fn from_type(&self) -> TokenStream {
+        match self.attrs.conversion.as_ref().and_then(|c| c.via.as_ref()) {
+            Some(via) => quote!(#via),
+            None => unoptional_type(self.ty),
+        }
+    }
+
     pub(crate) fn source_span(&self) -> Span {
         if let Some(source_attr) = &self.attrs.source {
             source_attr.path().get_ident().unwrap().span()
@@ -68,7 +78,9 @@ fn from_field<'a, 'b>(fields: &'a [Field<'b>]) -> Option<&'a Field<'b>> {
 
 fn source_field<'a, 'b>(fields: &'a [Field<'b>]) -> Option<&'a Field<'b>> {
     for field in fields {
-        if field.attrs.from.is_some() || field.attrs.source.is_some() {
+        // A field with a user defined conversion is not necessarily an error.
+        let is_from_source = field.attrs.from.is_some() && field.attrs.conversion.is_none();
+        if is_from_source || field.attrs.source.is_some() {
             return Some(field);
         }
     }
@@ -1,7 +1,10 @@
 use proc_macro2::TokenStream;
 
 use quote::{format_ident, quote};
-use syn::{GenericArgument, Member, PathArguments, Type};
+use syn::ext::IdentExt;
+use syn::{
+    Attribute, Expr, ExprLit, GenericArgument, Ident, Lit, Member, Meta, PathArguments, Type,
+};
 
 use crate::ast::Field;
 
@@ -27,6 +30,15 @@ pub fn from_initializer(from_field: &Field) -> TokenStream {
     })
 }
 
+/// Generates the user defined conversion of `#[from(via = Type, with = path)]`.
+pub fn from_conversion(from_field: &Field) -> Option<TokenStream> {
+    let conversion = from_field.attrs.conversion.as_ref()?;
+    match &conversion.with {
+        Some(with) => Some(quote!(let source = #with(source);)),
+        None => Some(quote!(let source = ::core::convert::From::from(source);)),
+    }
+}
+
 pub fn type_is_option(ty: &Type) -> bool {
     type_parameter_of_option(ty).is_some()
 }
@@ -36,14 +48,23 @@ pub fn unoptional_type(ty: &Type) -> TokenStream {
     quote!(#unoptional)
 }
 
+pub fn type_is_vec(ty: &Type) -> bool {
+    type_parameter_of(ty, "Vec").is_some()
+}
+
 pub fn type_parameter_of_option(ty: &Type) -> Option<&Type> {
+    type_parameter_of(ty, "Option")
+}
+
+/// Returns the type parameter of a wrapper type like `Option<T>`, `Box<T>` or `Vec<T>`.
+pub fn type_parameter_of<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
     let path = match ty {
         Type::Path(ty) => &ty.path,
         _ => return None,
     };
 
     let last = path.segments.last().unwrap();
-    if last.ident != "Option" {
+    if last.ident != wrapper {
         return None;
     }
 
@@ -62,6 +83,148 @@ pub fn type_parameter_of_option(ty: &Type) -> Option<&Type> {
     }
 }
 
+/// Generates an expression that visits the traces of errors nested in a field value.
+///
+/// The wrapper types `Box<T>`, `Vec<T>` and `Option<T>` are unwrapped
+/// and the inner value is passed to a probe, that decides whether the value has a trace.
+/// Vectors are only iterated if their elements are traceable.
+pub fn nested_probe(ty: &Type, value: TokenStream) -> TokenStream {
+    if let Some(inner) = type_parameter_of(ty, "Box") {
+        nested_probe(inner, quote!(&**#value))
+    } else if let Some(inner) = type_parameter_of(ty, "Vec") {
+        let element = unwrapped_type(inner);
+        let probe = nested_probe(inner, quote!(item));
+        quote! {
+            if {
+                use errore::__private::{TraceableType as _, UntraceableType as _};
+                (&&errore::__private::TypeProbe::<#element>(::core::marker::PhantomData)).is_traceable()
+            } {
+                for item in (#value).iter() {
+                    #probe
+                }
+            }
+        }
+    } else if let Some(inner) = type_parameter_of(ty, "Option") {
+        let probe = nested_probe(inner, quote!(item));
+        quote! {
+            if let ::core::option::Option::Some(item) = (#value).as_ref() {
+                #probe
+            }
+        }
+    } else {
+        quote! {
+            (&&&errore::__private::NestedProbe(#value)).probe_nested(visitor);
+        }
+    }
+}
+
+/// Returns the innermost type of nested `Box<T>`, `Vec<T>` and `Option<T>` wrappers.
+fn unwrapped_type(ty: &Type) -> &Type {
+    ["Box", "Vec", "Option"]
+        .into_iter()
+        .find_map(|wrapper| type_parameter_of(ty, wrapper))
+        .map_or(ty, unwrapped_type)
+}
+
+/// Joins the lines of all doc comments to a single string.
+pub fn doc_string(attrs: &[Attribute]) -> String {
+    let lines = attrs.iter().filter_map(|attr| match &attr.meta {
+        Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => Some(lit.value()),
+            _ => None,
+        },
+        _ => None,
+    });
+    let lines = lines
+        .map(|line| line.strip_prefix(' ').map(str::to_owned).unwrap_or(line))
+        .collect::<Vec<_>>();
+    lines.join("\n")
+}
+
+/// Returns the type as readable string, for e.g. `Box<a::Ec>` instead of `Box < a :: Ec >`.
+pub fn type_string(ty: &TokenStream) -> String {
+    ty.to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" < ", "<")
+        .replace("< ", "<")
+        .replace(" <", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
+
+/// Generates a statement that passes the value of a field to a `FieldVisitor`.
+///
+/// Primitive types are passed to the specialized methods of the visitor,
+/// other types are passed as `Debug` if the trait is implemented.
+pub fn field_record(field: &Field) -> TokenStream {
+    let (name, binding) = match &field.member {
+        Member::Named(ident) => (ident.unraw().to_string(), ident.clone()),
+        Member::Unnamed(index) => (index.index.to_string(), format_ident!("_{}", index)),
+    };
+    if field.attrs.redact.is_some() {
+        return quote! {
+            visitor.record_str(#name, errore::__private::Redacted::STR);
+        };
+    }
+
+    let is_str = match field.ty {
+        Type::Reference(reference) => {
+            matches!(&*reference.elem, Type::Path(path) if path.path.is_ident("str"))
+        }
+        Type::Path(path) => path.path.is_ident("String"),
+        _ => false,
+    };
+    if is_str {
+        return quote! {
+            visitor.record_str(#name, #binding);
+        };
+    }
+
+    let primitive = match field.ty {
+        Type::Path(path) if path.qself.is_none() => path.path.get_ident().map(Ident::to_string),
+        _ => None,
+    };
+    match primitive.as_deref() {
+        Some("bool") => quote! {
+            visitor.record_bool(#name, *#binding);
+        },
+        Some("i8" | "i16" | "i32" | "i64" | "isize") => quote! {
+            visitor.record_i64(#name, *#binding as i64);
+        },
+        Some("u8" | "u16" | "u32" | "u64" | "usize") => quote! {
+            visitor.record_u64(#name, *#binding as u64);
+        },
+        Some("f32" | "f64") => quote! {
+            visitor.record_f64(#name, *#binding as f64);
+        },
+        _ => quote! {
+            (&&errore::__private::FieldProbe(#binding)).probe_field(#name, visitor);
+        },
+    }
+}
+
+/// Converts an identifier in camel case to snake case, for e.g. `HttpRequest` to `http_request`.
+pub fn snake_case(ident: &Ident) -> String {
+    let name = ident.to_string();
+    let chars = name.trim_start_matches("r#").chars().collect::<Vec<char>>();
+    let mut snake = String::with_capacity(chars.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_lower) {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
 pub fn fields_pat(fields: &[Field]) -> TokenStream {
     let mut members = fields.iter().map(|field| &field.member).peekable();
     match members.peek() {
@@ -1,6 +1,5 @@
 use std::collections::BTreeSet as Set;
 
-use quote::ToTokens;
 use syn::{Error, GenericArgument, Member, PathArguments, Result, Type};
 
 use crate::ast::{DeriveType, Enum, Field, Input, Struct, Variant};
@@ -18,6 +17,12 @@ impl Input<'_> {
 impl Struct<'_> {
     fn validate(&self) -> Result<()> {
         check_non_field_attrs(&self.attrs)?;
+        if let Some(kind) = &self.attrs.kind {
+            return Err(Error::new_spanned(
+                kind.original,
+                "#[errore(kind)] is only supported on enums",
+            ));
+        }
         if let Some(transparent) = self.attrs.transparent {
             if self.fields.len() != 1 {
                 return Err(Error::new_spanned(
@@ -55,10 +60,29 @@ impl Enum<'_> {
             }
         }
 
+        if let Some(code) = &self.attrs.code {
+            return Err(Error::new(
+                code.span,
+                "not expected here; the code belongs on a specific variant",
+            ));
+        }
+
+        let mut codes = Set::new();
+        for variant in &self.variants {
+            if let Some(code) = &variant.attrs.code {
+                if !codes.insert(&code.value) {
+                    return Err(Error::new(
+                        code.span,
+                        format!("duplicate error code \"{}\"", code.value),
+                    ));
+                }
+            }
+        }
+
         let mut from_types = Set::new();
         for variant in &self.variants {
             if let Some(from_field) = variant.from_field() {
-                let repr = from_field.ty.to_token_stream().to_string();
+                let repr = from_field.from_type().to_string();
                 if !from_types.insert(repr) {
                     return Err(Error::new_spanned(
                         from_field.original,
@@ -89,6 +113,7 @@ impl Enum<'_> {
 impl Variant<'_> {
     fn validate(&self) -> Result<()> {
         check_non_field_attrs(&self.attrs)?;
+        check_errore_attr(&self.attrs)?;
         if self.attrs.transparent.is_some() {
             if self.fields.len() != 1 {
                 return Err(Error::new_spanned(
@@ -119,10 +144,74 @@ impl Field<'_> {
                 "not expected here; the #[error(...)] attribute belongs on top of a struct or an enum variant",
             ));
         }
+        if let Some(code) = &self.attrs.code {
+            return Err(Error::new_spanned(
+                code.original,
+                "not expected here; the #[errore(...)] attribute belongs on top of a struct, an enum or an enum variant",
+            ));
+        }
+        if let Some(severity) = &self.attrs.severity {
+            return Err(Error::new_spanned(
+                severity.original,
+                "not expected here; the #[errore(...)] attribute belongs on top of a struct, an enum or an enum variant",
+            ));
+        }
+        if let Some(classification) = &self.attrs.classification {
+            return Err(Error::new_spanned(
+                classification.original,
+                "not expected here; the #[errore(...)] attribute belongs on top of a struct, an enum or an enum variant",
+            ));
+        }
+        if let Some(status) = &self.attrs.status {
+            return Err(Error::new_spanned(
+                status.original,
+                "not expected here; the #[errore(...)] attribute belongs on top of a struct, an enum or an enum variant",
+            ));
+        }
+        if let Some(grpc) = &self.attrs.grpc {
+            return Err(Error::new_spanned(
+                grpc.original,
+                "not expected here; the #[errore(...)] attribute belongs on top of a struct, an enum or an enum variant",
+            ));
+        }
+        if let Some(diagnostic) = self.attrs.help.as_ref().or(self.attrs.note.as_ref()) {
+            return Err(Error::new_spanned(
+                diagnostic.original,
+                "not expected here; the #[errore(...)] attribute belongs on top of a struct, an enum or an enum variant",
+            ));
+        }
+        if let Some(backtrace) = &self.attrs.backtrace {
+            return Err(Error::new_spanned(
+                backtrace,
+                "not expected here; the #[backtrace] attribute belongs on top of a struct, an enum or an enum variant",
+            ));
+        }
+        if let Some(label) = self.attrs.name.as_ref().or(self.attrs.target.as_ref()) {
+            return Err(Error::new_spanned(
+                label.original,
+                "not expected here; the #[errore(...)] attribute belongs on top of a struct, an enum or an enum variant",
+            ));
+        }
+        check_errore_attr(&self.attrs)?;
         Ok(())
     }
 }
 
+fn check_errore_attr(attrs: &Attrs) -> Result<()> {
+    let original = attrs
+        .context
+        .as_ref()
+        .map(|context| context.original)
+        .or(attrs.kind.as_ref().map(|kind| kind.original));
+    if let Some(original) = original {
+        return Err(Error::new_spanned(
+            original,
+            "not expected here; the #[errore(...)] attribute belongs on top of a struct or an enum",
+        ));
+    }
+    Ok(())
+}
+
 fn check_non_field_attrs(attrs: &Attrs) -> Result<()> {
     if let Some(from) = &attrs.from {
         return Err(Error::new_spanned(
@@ -136,6 +225,12 @@ fn check_non_field_attrs(attrs: &Attrs) -> Result<()> {
             "not expected here; the #[source] attribute belongs on a specific field",
         ));
     }
+    if let Some(redact) = &attrs.redact {
+        return Err(Error::new_spanned(
+            redact,
+            "not expected here; the #[redact] attribute belongs on a specific field",
+        ));
+    }
     if let Some(display) = &attrs.display {
         if attrs.transparent.is_some() {
             return Err(Error::new_spanned(
@@ -227,7 +322,7 @@ fn contains_non_static_lifetime(ty: &Type) -> bool {
         Type::Reference(ty) => ty
             .lifetime
             .as_ref()
-            .map_or(false, |lifetime| lifetime.ident != "static"),
+            .is_some_and(|lifetime| lifetime.ident != "static"),
         _ => false, // maybe implement later if there are common other cases
     }
 }
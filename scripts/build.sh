@@ -26,6 +26,9 @@ function build_no_std {
 
 println "Try to build for host architecture '$RUNNER_ARCH'"
 cargo +$CHANNEL build --all
+println "Try to build for host architecture '$RUNNER_ARCH' without std"
+cargo +$CHANNEL build --package errore --no-default-features --features nightly
+cargo +$CHANNEL build --package errore --no-default-features --features nightly,serde
 println "Try to build example-optional with 'thiserror' feature"
 cargo +$CHANNEL build --package example-optional --no-default-features --features thiserror
 println "Try to build example-optional with 'errore' feature"
@@ -1,9 +1,14 @@
+extern crate alloc;
+
+use alloc::string::String;
 use core::{
     fmt::{self},
     ops::{Deref, DerefMut},
 };
 
+use crate::field::FieldVisitor;
 use crate::hash::fnv1a_hash_64;
+use crate::trace::TraceContext;
 
 /// Represents an identifier hash.
 #[derive(Clone, Copy, Default, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -29,42 +34,42 @@ impl Id {
 impl PartialEq<i16> for Id {
     #[inline]
     fn eq(&self, other: &i16) -> bool {
-        self.0 == (*other).try_into().unwrap_or_default()
+        self.0 == u64::try_from(*other).unwrap_or_default()
     }
 }
 
 impl PartialEq<i32> for Id {
     #[inline]
     fn eq(&self, other: &i32) -> bool {
-        self.0 == (*other).try_into().unwrap_or_default()
+        self.0 == u64::try_from(*other).unwrap_or_default()
     }
 }
 
 impl PartialEq<i64> for Id {
     #[inline]
     fn eq(&self, other: &i64) -> bool {
-        self.0 == (*other).try_into().unwrap_or_default()
+        self.0 == u64::try_from(*other).unwrap_or_default()
     }
 }
 
 impl PartialEq<i128> for Id {
     #[inline]
     fn eq(&self, other: &i128) -> bool {
-        self.0 == (*other).try_into().unwrap_or_default()
+        self.0 == u64::try_from(*other).unwrap_or_default()
     }
 }
 
 impl PartialEq<u16> for Id {
     #[inline]
     fn eq(&self, other: &u16) -> bool {
-        self.0 == (*other).into()
+        self.0 == u64::from(*other)
     }
 }
 
 impl PartialEq<u32> for Id {
     #[inline]
     fn eq(&self, other: &u32) -> bool {
-        self.0 == (*other).into()
+        self.0 == u64::from(*other)
     }
 }
 
@@ -78,14 +83,14 @@ impl PartialEq<u64> for Id {
 impl PartialEq<u128> for Id {
     #[inline]
     fn eq(&self, other: &u128) -> bool {
-        self.0 == (*other).try_into().unwrap_or_default()
+        self.0 == u64::try_from(*other).unwrap_or_default()
     }
 }
 
 impl PartialEq<usize> for Id {
     #[inline]
     fn eq(&self, other: &usize) -> bool {
-        self.0 == (*other).try_into().unwrap_or_default()
+        self.0 == u64::try_from(*other).unwrap_or_default()
     }
 }
 
@@ -147,6 +152,88 @@ impl From<u16> for Id {
     }
 }
 
+/// Describes how severe an error is.
+///
+/// The severity is assigned with `#[errore(severity = "warn" | "error" | "fatal")]`
+/// and defaults to [`Severity::Error`]. Transparent errors without a severity
+/// forward the severity of the error they wrap.
+#[derive(Clone, Copy, Default, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Severity {
+    /// The error is recoverable and can be reported as warning.
+    Warn,
+    /// The default severity for errors.
+    #[default]
+    Error,
+    /// The error is unrecoverable.
+    Fatal,
+}
+
+impl Severity {
+    /// Returns the severity as lowercase string like it is used in the `#[error]` attribute.
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Warn => "warn",
+            Severity::Error => "error",
+            Severity::Fatal => "fatal",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Describes whether an operation that failed with an error is worth to be retried.
+///
+/// The classification is assigned with `#[errore(transient)]` or `#[errore(permanent)]`
+/// and defaults to [`Classification::Unclassified`].
+/// The variants are ordered by precedence, see [`Classify`](crate::Classify).
+#[derive(Clone, Copy, Default, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Classification {
+    /// The error is not classified.
+    #[default]
+    Unclassified,
+    /// The error is temporary and the operation can be retried, for e.g. a timeout.
+    Transient,
+    /// The error will occur again and the operation should not be retried.
+    Permanent,
+}
+
+impl Classification {
+    /// Returns the classification as lowercase string like it is used in the `#[error]` attribute.
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Classification::Unclassified => "unclassified",
+            Classification::Transient => "transient",
+            Classification::Permanent => "permanent",
+        }
+    }
+
+    /// Returns `true` if the classification is [`Classification::Transient`].
+    #[inline]
+    pub const fn is_transient(&self) -> bool {
+        matches!(self, Classification::Transient)
+    }
+
+    /// Returns `true` if the classification is [`Classification::Permanent`].
+    #[inline]
+    pub const fn is_permanent(&self) -> bool {
+        matches!(self, Classification::Permanent)
+    }
+}
+
+impl fmt::Display for Classification {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Defines the metadata for an error type.
 ///
 /// <div class="warning">
@@ -178,6 +265,66 @@ pub trait Metadata {
 
     /// Returns `true` if the field forwards its [`Display`](std::fmt::Display) trait implementation to the source.
     fn is_transparent(&self) -> bool;
+
+    /// Returns the stable error code assigned with `#[errore(code = ...)]`.
+    ///
+    /// Unlike [`Metadata::id`], the code doesn't change when the error is moved or renamed.
+    fn code(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the severity assigned with `#[errore(severity = ...)]`.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Returns the classification assigned with `#[errore(transient)]` or `#[errore(permanent)]`.
+    fn classification(&self) -> Classification {
+        Classification::Unclassified
+    }
+
+    /// Returns the HTTP status code assigned with `#[errore(status = ...)]`.
+    fn status(&self) -> Option<u16> {
+        None
+    }
+
+    /// Returns the gRPC status code assigned with `#[errore(grpc = "...")]`, for e.g. `NOT_FOUND`.
+    fn grpc_code(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the help message assigned with `#[errore(help = "...")]`.
+    ///
+    /// The message supports the same format-argument interpolation as the error message.
+    fn help(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the note assigned with `#[errore(note = "...")]`.
+    ///
+    /// The message supports the same format-argument interpolation as the error message.
+    fn note(&self) -> Option<String> {
+        None
+    }
+
+    /// Visits the name and value of every field of the error.
+    ///
+    /// For enums only the fields of the current variant are visited.
+    /// See [`FieldVisitor`] for details.
+    #[allow(unused_variables)]
+    fn visit_fields(&self, visitor: &mut dyn FieldVisitor) {}
+
+    #[doc(hidden)]
+    /// Visits the trace contexts of errors that are nested in fields,
+    /// for e.g. `Box<Ec>` or `Vec<Ec>`.
+    #[allow(unused_variables)]
+    fn visit_nested(&self, visitor: &mut dyn FnMut(&TraceContext)) {}
+
+    #[doc(hidden)]
+    /// Returns `true` if the error is declared with the `#[backtrace]` attribute.
+    fn has_backtrace(&self) -> bool {
+        false
+    }
 }
 
 // These macros are used by the procedural macro `errore::error`.
@@ -223,3 +370,21 @@ macro_rules! impl_static_var {
             errore::__private::Lazy::new(|| $body);
     };
 }
+
+#[cfg(all(feature = "ctor", not(miri)))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_descriptor {
+    ($descriptor:expr) => {
+        errore::__private::submit! {
+            $descriptor
+        }
+    };
+}
+
+#[cfg(any(not(feature = "ctor"), miri))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_descriptor {
+    ($descriptor:expr) => {};
+}
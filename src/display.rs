@@ -1,4 +1,4 @@
-use core::fmt::Display;
+use core::fmt::{self, Debug, Display};
 
 #[doc(hidden)]
 pub trait AsDisplay<'a> {
@@ -37,3 +37,26 @@ impl<'a> AsDisplay<'a> for std::path::PathBuf {
         self.display()
     }
 }
+
+/// Placeholder that is formatted instead of a field with the `#[redact]` attribute.
+#[doc(hidden)]
+pub struct Redacted;
+
+impl Redacted {
+    /// Text that replaces the value of a redacted field.
+    pub const STR: &'static str = "***";
+}
+
+impl Debug for Redacted {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::STR)
+    }
+}
+
+impl Display for Redacted {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::STR)
+    }
+}
@@ -43,12 +43,38 @@ where
         }
     }
 
+    /// Rebinds the downcasted error to another lifetime, since the error is kept alive by reference counting.
+    #[inline]
+    pub(crate) fn rebind<'b>(self) -> Downcasted<'b, T> {
+        Downcasted::new(self.origin)
+    }
+
+    /// Returns the shared error with its actual type.
+    #[inline]
+    pub fn into_arc(self) -> Arc<T> {
+        let _ = self.downcast_ref();
+        // SAFETY: `downcast_ref` panics unless the error behind `origin` is a `T`,
+        // so the data pointer of the unsized `Arc<dyn Error>` points to a `T` allocated by an `Arc<T>`.
+        // Casting the fat pointer to a thin one keeps the address and drops the vtable,
+        // which is the pointer `Arc::<T>::from_raw` expects. The reference count is moved
+        // from `origin` to the returned `Arc` and `origin` is not used afterwards.
+        unsafe { Arc::from_raw(Arc::into_raw(self.origin).cast::<T>()) }
+    }
+
+    /// Returns the owned error if it isn't shared anymore.
+    ///
+    /// Otherwise the downcasted error is returned unchanged.
+    #[inline]
+    pub fn into_inner(self) -> Result<T, Self> {
+        Arc::try_unwrap(self.into_arc()).map_err(|origin| Self::new(origin))
+    }
+
     /// Returns a downcasted error reference.
     #[inline]
     fn downcast_ref(&self) -> &T {
-        return self.origin.downcast_ref::<T>().expect(
-            "Implementation of trait 'Extract' must check the value. Please open an issue.",
-        );
+        self.origin
+            .downcast_ref::<T>()
+            .expect("Implementation of trait 'Extract' must check the value. Please open an issue.")
     }
 }
 
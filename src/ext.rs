@@ -0,0 +1,166 @@
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use core::any::Any;
+use core::error::Error;
+
+use crate::data::Metadata;
+use crate::dlog;
+use crate::trace::{TraceRecord, Traceable};
+
+/// Shorthand macro for [`Result::Err`] in order to create an error context from a related enum or struct type.
+///
+/// The context type defaults to `Ec` and can be passed as first argument,
+/// if it was renamed with `#[errore(context = ...)]`.
+#[macro_export]
+macro_rules! err {
+    ($enum_error:expr) => {
+        Err(Ec::new($enum_error))
+    };
+    ($context:path, $enum_error:expr) => {
+        Err(<$context>::new($enum_error))
+    };
+}
+
+/// Extension methods for [`core::result::Result`] with an error context.
+///
+/// Without the `nightly` feature, the `?` operator can't be hooked by errore.
+/// Conversions between error contexts are still recorded by the [`From`] implementation,
+/// but an error that is propagated with the same type needs to be recorded explicitly:
+///
+/// ```ignore
+/// fn load() -> Result<(), Ec> {
+///     read().traced()?;
+///     Ok(())
+/// }
+/// ```
+///
+/// Messages and values are attached like with the `nightly` [`Result`](crate::result::Result).
+pub trait ResultExt<T, E> {
+    /// Records the location of the caller in the trace of the error.
+    fn traced(self) -> core::result::Result<T, E>;
+
+    /// Attaches a message to the trace of the error, without declaring a new error type.
+    ///
+    /// If [`Err`], a record with the message and the location of the caller
+    /// is appended to the trace and rendered below the current node.
+    fn context<C>(self, context: C) -> core::result::Result<T, E>
+    where
+        C: Into<Cow<'static, str>>;
+
+    /// Attaches a message to the trace of the error, see [`ResultExt::context()`].
+    ///
+    /// The message is only created if [`Err`].
+    fn with_context<C, F>(self, f: F) -> core::result::Result<T, E>
+    where
+        C: Into<Cow<'static, str>>,
+        F: FnOnce() -> C;
+
+    /// Attaches a value to the current record of the trace, for e.g. the query or the retry attempt.
+    ///
+    /// The values are returned in propagation order by `attachments::<T>()`.
+    fn attach<A>(self, value: A) -> core::result::Result<T, E>
+    where
+        A: Any + Send + Sync;
+
+    /// Attaches a value to the current record of the trace, see [`ResultExt::attach()`].
+    ///
+    /// The value is only created if [`Err`].
+    fn attach_with<A, F>(self, f: F) -> core::result::Result<T, E>
+    where
+        A: Any + Send + Sync,
+        F: FnOnce() -> A;
+}
+
+impl<T, E> ResultExt<T, E> for core::result::Result<T, E>
+where
+    E: Error + Traceable + Metadata,
+{
+    #[track_caller]
+    #[inline]
+    fn traced(self) -> core::result::Result<T, E> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(mut e) => {
+                if let Some(ctx) = e.trace_ref() {
+                    let rec = TraceRecord::new(&e, ctx);
+                    dlog!("insert ({})\n\tin {}", rec.name, rec.location);
+                    e.insert(rec);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    #[track_caller]
+    #[inline]
+    fn context<C>(self, context: C) -> core::result::Result<T, E>
+    where
+        C: Into<Cow<'static, str>>,
+    {
+        match self {
+            Ok(v) => Ok(v),
+            Err(mut e) => {
+                insert_context(&mut e, context.into());
+                Err(e)
+            }
+        }
+    }
+
+    #[track_caller]
+    #[inline]
+    fn with_context<C, F>(self, f: F) -> core::result::Result<T, E>
+    where
+        C: Into<Cow<'static, str>>,
+        F: FnOnce() -> C,
+    {
+        match self {
+            Ok(v) => Ok(v),
+            Err(mut e) => {
+                insert_context(&mut e, f().into());
+                Err(e)
+            }
+        }
+    }
+
+    #[inline]
+    fn attach<A>(self, value: A) -> core::result::Result<T, E>
+    where
+        A: Any + Send + Sync,
+    {
+        self.map_err(|mut e| {
+            if let Some(ctx) = e.trace_mut() {
+                ctx.attach(value);
+            }
+            e
+        })
+    }
+
+    #[inline]
+    fn attach_with<A, F>(self, f: F) -> core::result::Result<T, E>
+    where
+        A: Any + Send + Sync,
+        F: FnOnce() -> A,
+    {
+        self.map_err(|mut e| {
+            if let Some(ctx) = e.trace_mut() {
+                ctx.attach(f());
+            }
+            e
+        })
+    }
+}
+
+/// Appends a record with the message and the location of the caller to the trace.
+#[track_caller]
+pub(crate) fn insert_context<E>(e: &mut E, context: Cow<'static, str>)
+where
+    E: Traceable + Metadata,
+{
+    if let Some(ctx) = e.trace_ref() {
+        let mut rec = TraceRecord::new(e, ctx);
+        rec.context = Some(context);
+        dlog!("insert ({}) context\n\tin {}", rec.name, rec.location);
+        e.insert(rec);
+    }
+}
@@ -32,4 +32,20 @@ pub trait Extract {
     fn has<'a, E>(&'a self) -> bool
     where
         E: Error + Extractable + 'static;
+
+    /// Iterates the error chain and moves an error out by its type.
+    ///
+    /// The error chain is dropped afterwards. If the error is still shared,
+    /// for e.g. by another [`Downcasted`], it is returned as [`Err`] and can be accessed with [`Downcasted::into_arc()`].
+    ///
+    /// This function can only be used for error structs or enums that use the derive procedural macro `errore::error`.
+    fn take<E>(self) -> Option<Result<E, Downcasted<'static, E>>>
+    where
+        Self: Sized,
+        E: Error + Extractable + 'static,
+    {
+        let downcasted = self.get::<E>()?.rebind();
+        drop(self);
+        Some(downcasted.into_inner())
+    }
 }
@@ -0,0 +1,87 @@
+use core::fmt::Debug;
+
+/// A visitor for the fields of an error.
+///
+/// The fields are visited with [`Metadata::visit_fields()`](crate::Metadata::visit_fields)
+/// or [`TraceRecord::visit_fields()`](crate::TraceRecord::visit_fields),
+/// which allows subscribers to export fields as key-value pairs without knowing the type of the error:
+///
+/// ```ignore
+/// struct Fields(Vec<(String, String)>);
+///
+/// impl FieldVisitor for Fields {
+///     fn record_debug(&mut self, name: &str, value: &dyn Debug) {
+///         self.0.push((name.into(), format!("{:?}", value)));
+///     }
+/// }
+/// ```
+///
+/// Primitive values are passed to the specialized methods,
+/// which forward to [`record_debug()`](FieldVisitor::record_debug) by default.
+/// Fields of unnamed structs or variants are named by their index, for e.g. `0`.
+/// Fields with the `#[redact]` attribute are passed as `***` to [`record_str()`](FieldVisitor::record_str).
+pub trait FieldVisitor {
+    /// Visits a field that implements [`Debug`].
+    fn record_debug(&mut self, name: &str, value: &dyn Debug);
+
+    /// Visits a field of type [`str`] or `String`.
+    fn record_str(&mut self, name: &str, value: &str) {
+        self.record_debug(name, &value)
+    }
+
+    /// Visits a field of type [`bool`].
+    fn record_bool(&mut self, name: &str, value: bool) {
+        self.record_debug(name, &value)
+    }
+
+    /// Visits a field of a signed integer type up to 64 bits.
+    fn record_i64(&mut self, name: &str, value: i64) {
+        self.record_debug(name, &value)
+    }
+
+    /// Visits a field of an unsigned integer type up to 64 bits.
+    fn record_u64(&mut self, name: &str, value: u64) {
+        self.record_debug(name, &value)
+    }
+
+    /// Visits a field of a floating point type.
+    fn record_f64(&mut self, name: &str, value: f64) {
+        self.record_debug(name, &value)
+    }
+}
+
+#[doc(hidden)]
+/// Wrapper to visit a field without knowing whether it implements [`Debug`].
+///
+/// Uses autoref specialization with the following priority:
+/// 1. [`Debug`] passes the field to [`FieldVisitor::record_debug`].
+/// 2. Other fields are skipped.
+pub struct FieldProbe<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait DebugField {
+    fn probe_field(&self, name: &str, visitor: &mut dyn FieldVisitor);
+}
+
+impl<'a, T> DebugField for &FieldProbe<'a, T>
+where
+    T: Debug + ?Sized,
+{
+    #[inline]
+    fn probe_field(&self, name: &str, visitor: &mut dyn FieldVisitor) {
+        visitor.record_debug(name, &self.0)
+    }
+}
+
+#[doc(hidden)]
+pub trait OpaqueField {
+    fn probe_field(&self, name: &str, visitor: &mut dyn FieldVisitor);
+}
+
+impl<'a, T> OpaqueField for FieldProbe<'a, T>
+where
+    T: ?Sized,
+{
+    #[inline]
+    fn probe_field(&self, _name: &str, _visitor: &mut dyn FieldVisitor) {}
+}
@@ -4,7 +4,7 @@ use alloc::string::{String, ToString};
 use core::fmt::{self, Debug};
 use core::marker::{Send, Sync};
 
-use crate::data::{Id, Metadata};
+use crate::data::{Id, Metadata, Severity};
 use crate::trace::{TraceAccess, TraceContext, TraceRecord};
 
 /// A handler to format [`TraceRecord`] and [`TraceContext`] types.
@@ -13,10 +13,13 @@ use crate::trace::{TraceAccess, TraceContext, TraceRecord};
 pub trait Formatter: Sync + Send {
     /// Formats a [`TraceRecord`] and passes it to the internal [`Display`](std::fmt::Display) trait implementation.
     fn format_record(&self, rec: &TraceRecord, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<{}>", rec.name)?;
+        if let Some(code) = &rec.code {
+            write!(f, " [{}]", code)?;
+        }
         write!(
             f,
-            "<{}> {} at {}",
-            rec.name,
+            " {} at {}",
             rec.error_ref().map(|e| e.to_string()).unwrap_or_default(),
             rec.location
         )
@@ -29,24 +32,41 @@ pub trait Formatter: Sync + Send {
         ctx: &TraceContext,
         f: &mut fmt::Formatter,
     ) -> fmt::Result {
-        write!(f, "{}: ", span.name())?;
+        write!(f, "{}", span.name())?;
+        if let Some(code) = span.code() {
+            write!(f, " [{}]", code)?;
+        }
+        write!(f, ": ")?;
         span.display(f)?;
         write!(f, "\n    at {}", ctx.first().location)
     }
 
+    /// Formats the body of an HTTP response for an error context,
+    /// for e.g. with the `axum` or `actix-web` feature.
+    ///
+    /// The body is sent to clients, so only the error message is written by default.
+    /// Names, locations and the trace are never included unless this method is overridden.
+    fn format_response(
+        &self,
+        span: &(dyn Metadata + 'static),
+        ctx: &TraceContext,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        span.display(f)
+    }
+
     /// Formats a [`TraceContext`] type and passes it to the internal [`Display`](std::fmt::Display) trait implementation.
     fn format_trace(&self, ctx: &TraceContext, f: &mut fmt::Formatter) -> fmt::Result {
         #[derive(Default)]
         struct Fmt {
             node: bool,
             fmt_error: bool,
-            last_node: bool,
         }
 
         // Map trace records with additional data.
         let mut last_id: Id = Id::default();
         let trace = ctx.iter();
-        let mut nodes = trace
+        let nodes = trace
             .map(|tr| {
                 let r = (
                     tr,
@@ -55,7 +75,6 @@ pub trait Formatter: Sync + Send {
                         // Always format the first error and avoid duplicate messages,
                         // when using the transparent attribute.
                         fmt_error: last_id.is_null() || !tr.is_transparent,
-                        last_node: false,
                     },
                 );
                 last_id = tr.id;
@@ -63,17 +82,21 @@ pub trait Formatter: Sync + Send {
             })
             .collect::<alloc::vec::Vec<(&TraceRecord, Fmt)>>();
 
-        // Mark last node.
-        for (_, f) in nodes.iter_mut().rev() {
-            if f.node {
-                f.last_node = true;
-                break;
-            }
-        }
+        // Find the last node, its records are not followed by another node.
+        let last_node = nodes.iter().rposition(|(_, f)| f.node).unwrap_or_default();
 
-        // Write the last emitted error.
+        // Write the last emitted error labeled with the highest severity of the trace.
         // The error message is skipped because it is included in the trace itself.
-        writeln!(f, "Error: {}", ctx.last().name)?;
+        let label = match ctx.severity() {
+            Severity::Warn => "Warning",
+            Severity::Error => "Error",
+            Severity::Fatal => "Fatal",
+        };
+        write!(f, "{}: {}", label, ctx.last().name)?;
+        match &ctx.last().code {
+            Some(code) => writeln!(f, " [{}]", code)?,
+            None => writeln!(f)?,
+        }
 
         for i in 0..nodes.len() {
             let (tr, n) = nodes.get(i).unwrap();
@@ -87,7 +110,7 @@ pub trait Formatter: Sync + Send {
             let mut lvl1_node = "├";
             let mut lvl0_newline = "";
             if let Some(next) = next {
-                if n.last_node {
+                if i >= last_node {
                     lvl0_node = "╰";
                     lvl0_continue = " ";
                 } else {
@@ -106,7 +129,10 @@ pub trait Formatter: Sync + Send {
 
             // Write head node on first error or when the span type changes.
             if n.node {
-                let node_msg = format!("{}─▶ <{}>", lvl0_node, tr.name);
+                let node_msg = match &tr.code {
+                    Some(code) => format!("{}─▶ <{}> [{}]", lvl0_node, tr.name, code),
+                    None => format!("{}─▶ <{}>", lvl0_node, tr.name),
+                };
                 write!(f, "{}", node_msg)?;
 
                 if n.fmt_error {
@@ -122,32 +148,76 @@ pub trait Formatter: Sync + Send {
                             &error_msg,
                             textwrap::Options::new(70).subsequent_indent(&format!(
                                 "│   │{}",
-                                " ".repeat(node_msg.len().checked_sub(10).unwrap_or(0))
+                                " ".repeat(node_msg.len().saturating_sub(10))
                             )),
                         );
 
                         // Write wrapped lines.
                         write!(f, " ")?;
                         for m in msg {
-                            write!(f, "{}\n", m)?;
+                            writeln!(f, "{}", m)?;
                         }
                     }
                     #[cfg(not(feature = "std"))]
                     {
-                        write!(f, "{}\n", error_msg)?;
+                        writeln!(f, "{}", error_msg)?;
                     }
                 } else {
                     // write!(f, " [transparent]\n")?;
-                    write!(f, "\n")?;
+                    writeln!(f)?;
+                }
+
+                // Write help and note messages below the node.
+                let diagnostics = [("help", tr.help()), ("note", tr.note())];
+                for (label, msg) in diagnostics {
+                    let Some(msg) = msg else { continue };
+                    for (j, line) in msg.lines().enumerate() {
+                        if j == 0 {
+                            writeln!(f, "{}   = {}: {}", lvl0_continue, label, line)?;
+                        } else {
+                            writeln!(
+                                f,
+                                "{}     {}  {}",
+                                lvl0_continue,
+                                " ".repeat(label.len()),
+                                line
+                            )?;
+                        }
+                    }
+                }
+
+                // Write the traces of nested errors below the node.
+                let mut nested = alloc::vec::Vec::new();
+                tr.visit_nested(|ctx| nested.push(ctx.to_string()));
+                for trace in nested {
+                    for (j, line) in trace.lines().enumerate() {
+                        let branch = if j == 0 { "├── " } else { "│   " };
+                        writeln!(f, "{}   {}{}", lvl0_continue, branch, line)?;
+                    }
                 }
             }
 
-            // Write location for every record.
-            write!(
-                f,
-                "{}   {}╴ {}{}{}",
-                lvl0_continue, lvl1_node, tr.location, lvl0_newline, newline
-            )?;
+            // Write location for every record, prefixed by the attached context message.
+            match tr.context() {
+                Some(context) => write!(
+                    f,
+                    "{}   {}╴ {} at {}{}{}",
+                    lvl0_continue, lvl1_node, context, tr.location, lvl0_newline, newline
+                )?,
+                None => write!(
+                    f,
+                    "{}   {}╴ {}{}{}",
+                    lvl0_continue, lvl1_node, tr.location, lvl0_newline, newline
+                )?,
+            }
+        }
+
+        // Append the backtrace of the origin error if it was enabled by the environment.
+        #[cfg(feature = "backtrace")]
+        if let Some(backtrace) = ctx.backtrace() {
+            if backtrace.status() == std::backtrace::BacktraceStatus::Captured {
+                write!(f, "\n\nBacktrace:\n{}", backtrace)?;
+            }
         }
 
         fmt::Result::Ok(())
@@ -0,0 +1,156 @@
+//! Conversions between error contexts and [`tonic::Status`].
+//!
+//! The code of the status is resolved with [`TraceContext::grpc_code()`](crate::TraceContext::grpc_code)
+//! and the message is rendered with [`Formatter::format_response()`](crate::formatter::Formatter::format_response).
+//! The status carries a `google.rpc.ErrorInfo` detail of the outermost non-transparent record:
+//!
+//! - `reason`: [`Metadata::name()`]
+//! - `domain`: [`Metadata::target()`]
+//! - `metadata`: the fields of the error, see [`FieldVisitor`](crate::FieldVisitor)
+//!
+//! On the client side the detail is used to rebuild a [`RemoteError`].
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::error::Error;
+use std::collections::HashMap;
+
+use tonic::{Code, Status};
+use tonic_types::{ErrorDetails, StatusExt};
+
+use crate::data::{Id, Metadata};
+use crate::hash::fnv1a_hash_64;
+use crate::location::Location;
+use crate::remote::{Record, RemoteError};
+use crate::span::Span;
+use crate::value::{Fields, Value};
+
+/// The gRPC status codes with the names used by `#[errore(grpc = "...")]`.
+const CODES: &[(Code, &str)] = &[
+    (Code::Ok, "OK"),
+    (Code::Cancelled, "CANCELLED"),
+    (Code::Unknown, "UNKNOWN"),
+    (Code::InvalidArgument, "INVALID_ARGUMENT"),
+    (Code::DeadlineExceeded, "DEADLINE_EXCEEDED"),
+    (Code::NotFound, "NOT_FOUND"),
+    (Code::AlreadyExists, "ALREADY_EXISTS"),
+    (Code::PermissionDenied, "PERMISSION_DENIED"),
+    (Code::ResourceExhausted, "RESOURCE_EXHAUSTED"),
+    (Code::FailedPrecondition, "FAILED_PRECONDITION"),
+    (Code::Aborted, "ABORTED"),
+    (Code::OutOfRange, "OUT_OF_RANGE"),
+    (Code::Unimplemented, "UNIMPLEMENTED"),
+    (Code::Internal, "INTERNAL"),
+    (Code::Unavailable, "UNAVAILABLE"),
+    (Code::DataLoss, "DATA_LOSS"),
+    (Code::Unauthenticated, "UNAUTHENTICATED"),
+];
+
+fn code_from_name(name: &str) -> Code {
+    CODES
+        .iter()
+        .find(|(_, n)| *n == name)
+        .map_or(Code::Unknown, |(code, _)| *code)
+}
+
+fn code_name(code: Code) -> &'static str {
+    CODES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map_or("UNKNOWN", |(_, name)| name)
+}
+
+impl<T> Span<T>
+where
+    T: Error + Metadata + 'static,
+{
+    /// Converts the span into a status with an `ErrorInfo` detail.
+    ///
+    /// Defaults to [`Code::Unknown`] if no error declares a gRPC status code.
+    #[doc(hidden)]
+    pub fn grpc_status(&self) -> Status {
+        let mut fields = Fields::default();
+        let (reason, domain, code) = match &self.ctx {
+            Some(ctx) => {
+                let rec = ctx
+                    .trace
+                    .iter()
+                    .rev()
+                    .find(|r| !r.is_transparent)
+                    .unwrap_or_else(|| ctx.last());
+                rec.visit_fields(&mut fields);
+                (
+                    rec.name.to_string(),
+                    rec.target.to_string(),
+                    ctx.grpc_code().map(code_from_name),
+                )
+            }
+            None => {
+                self.visit_fields(&mut fields);
+                (
+                    self.name().to_string(),
+                    self.target().to_string(),
+                    self.grpc_code().map(code_from_name),
+                )
+            }
+        };
+        let metadata: HashMap<String, String> = fields
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        Status::with_error_details(
+            code.unwrap_or(Code::Unknown),
+            self.format_response(),
+            ErrorDetails::with_error_info(reason, domain, metadata),
+        )
+    }
+}
+
+/// Rebuilds the error from the `ErrorInfo` detail of the status.
+///
+/// The location of the record is the location of the conversion,
+/// since the status doesn't contain the trace of the server.
+/// If the status has no `ErrorInfo` detail, the record is named `tonic::Status`.
+impl From<Status> for RemoteError {
+    #[track_caller]
+    fn from(status: Status) -> Self {
+        let (name, target, fields) = match status.get_details_error_info() {
+            Some(info) => {
+                // Values are sent as strings, the order of the fields is lost.
+                let mut fields: Vec<_> = info
+                    .metadata
+                    .into_iter()
+                    .map(|(name, value)| (name, Value::Str(value)))
+                    .collect();
+                fields.sort_by(|a, b| a.0.cmp(&b.0));
+                (info.reason, info.domain, Fields(fields))
+            }
+            None => ("tonic::Status".into(), "tonic".into(), Fields::default()),
+        };
+
+        let record = Record {
+            id: Id::from(fnv1a_hash_64(name.as_bytes())),
+            target_id: Id::from(fnv1a_hash_64(target.as_bytes())),
+            name,
+            target,
+            location: Location::from(core::panic::Location::caller()),
+            is_transparent: false,
+            code: None,
+            severity: Default::default(),
+            classification: Default::default(),
+            status: None,
+            grpc_code: Some(code_name(status.code()).into()),
+            message: Some(status.message().into()),
+            context: None,
+            help: None,
+            note: None,
+            fields: Some(fields),
+            nested: Vec::new(),
+        };
+        RemoteError::from_records(alloc::vec![record])
+            .expect("Remote error must have at least one or more records. Please open an issue.")
+    }
+}
@@ -0,0 +1,56 @@
+//! Interoperability with the [`anyhow`](https://crates.io/crates/anyhow) crate.
+//!
+//! Errors of type [`anyhow::Error`] can be converted into an error context
+//! by declaring a variant with `#[from] anyhow::Error`.
+//! The other direction is provided by the [`IntoAnyhow`] trait.
+
+use crate::interop::TracedError;
+#[cfg(feature = "nightly")]
+use crate::result::Result;
+use crate::trace::Traceable;
+
+/// Converts an error context or a result into its [`anyhow`] counterpart.
+pub trait IntoAnyhow {
+    /// The converted type.
+    type Output;
+
+    /// Converts the error context into an [`anyhow::Error`] that carries the whole trace.
+    ///
+    /// Every trace record is attached as context line, which is rendered by the installed
+    /// [`Formatter`](crate::formatter::Formatter).
+    /// The trace itself can be accessed with `error.downcast_ref::<errore::interop::TracedError>()`.
+    fn into_anyhow(self) -> Self::Output;
+}
+
+impl<T> IntoAnyhow for T
+where
+    T: Traceable + Send + Sync + 'static,
+{
+    type Output = ::anyhow::Error;
+
+    fn into_anyhow(self) -> Self::Output {
+        let traced = TracedError::new(self);
+        let lines = traced.context_lines();
+        let mut error = ::anyhow::Error::new(traced);
+        for line in lines {
+            error = error.context(line);
+        }
+        error
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, E> IntoAnyhow for Result<T, E>
+where
+    E: Traceable + Send + Sync + 'static,
+{
+    type Output = ::anyhow::Result<T>;
+
+    #[inline]
+    fn into_anyhow(self) -> Self::Output {
+        match self {
+            Result::Ok(v) => ::anyhow::Result::Ok(v),
+            Result::Err(e) => ::anyhow::Result::Err(e.into_anyhow()),
+        }
+    }
+}
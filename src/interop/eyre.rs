@@ -0,0 +1,151 @@
+//! Interoperability with the [`eyre`](https://crates.io/crates/eyre) crate.
+//!
+//! Errors of type [`eyre::Report`] can be converted into an error context
+//! by declaring a variant with `#[from] eyre::Report`.
+//! The other direction is provided by the [`IntoReport`] trait.
+//!
+//! Reports can be formatted with the installed [`Formatter`](crate::formatter::Formatter)
+//! by installing the [`Handler`]:
+//!
+//! ```ignore
+//! fn main() -> eyre::Result<()> {
+//!     errore::interop::eyre::install()?;
+//!     Ok(())
+//! }
+//! ```
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::error::Error;
+use core::fmt;
+
+use crate::interop::TracedError;
+#[cfg(feature = "nightly")]
+use crate::result::Result;
+use crate::trace::{TraceAccess, TraceContext, Traceable};
+
+/// Converts an error context or a result into its [`eyre`] counterpart.
+pub trait IntoReport {
+    /// The converted type.
+    type Output;
+
+    /// Converts the error context into an [`eyre::Report`] that carries the whole trace.
+    ///
+    /// Every trace record is attached as context line, which is rendered by the installed
+    /// [`Formatter`](crate::formatter::Formatter).
+    /// The trace itself can be accessed with `report.downcast_ref::<errore::interop::TracedError>()`.
+    fn into_report(self) -> Self::Output;
+}
+
+impl<T> IntoReport for T
+where
+    T: Traceable + Send + Sync + 'static,
+{
+    type Output = ::eyre::Report;
+
+    fn into_report(self) -> Self::Output {
+        let traced = TracedError::new(self);
+        let lines = traced.context_lines();
+        let mut report = ::eyre::Report::new(traced);
+        for line in lines {
+            report = report.wrap_err(line);
+        }
+        report
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, E> IntoReport for Result<T, E>
+where
+    E: Traceable + Send + Sync + 'static,
+{
+    type Output = ::eyre::Result<T>;
+
+    #[inline]
+    fn into_report(self) -> Self::Output {
+        match self {
+            Result::Ok(v) => ::eyre::Result::Ok(v),
+            Result::Err(e) => ::eyre::Result::Err(e.into_report()),
+        }
+    }
+}
+
+/// An [`EyreHandler`](::eyre::EyreHandler) that delegates to the installed
+/// [`Formatter`](crate::formatter::Formatter).
+///
+/// If an error of the chain has a trace, the messages of the preceding errors are written
+/// followed by the formatted trace. Otherwise the error chain is listed.
+///
+/// Without the `nightly` feature only error contexts converted with [`IntoReport`] are formatted.
+#[derive(Clone, Debug, Default)]
+pub struct Handler;
+
+impl ::eyre::EyreHandler for Handler {
+    fn debug(&self, error: &(dyn Error + 'static), f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return fmt::Debug::fmt(error, f);
+        }
+
+        // Search for the first error that provides a trace,
+        // which is done by spans and converted error contexts.
+        let mut source = Some(error);
+        let mut contexts = 0usize;
+        while let Some(error) = source {
+            if provided_trace(error).is_some() {
+                break;
+            }
+            contexts += 1;
+            source = error.source();
+        }
+
+        let Some(ctx) = source.and_then(provided_trace) else {
+            write!(f, "{}", error)?;
+            let mut source = error.source();
+            if source.is_some() {
+                write!(f, "\n\nCaused by:")?;
+            }
+            let mut i = 0;
+            while let Some(error) = source {
+                write!(f, "\n    {}: {}", i, error)?;
+                source = error.source();
+                i += 1;
+            }
+            return Ok(());
+        };
+
+        // The context lines of converted error contexts are already part of the trace.
+        // Spans are wrapped by the error context, which is skipped as well.
+        if source.is_some_and(|error| error.is::<TracedError>()) {
+            contexts = contexts.saturating_sub(ctx.len());
+        } else {
+            contexts = contexts.saturating_sub(1);
+        }
+        let mut source = Some(error);
+        for _ in 0..contexts {
+            let Some(error) = source else { break };
+            writeln!(f, "{}", error)?;
+            source = error.source();
+        }
+        write!(f, "{}", ctx)
+    }
+}
+
+/// Returns the trace that is provided by the error.
+#[cfg(feature = "nightly")]
+fn provided_trace<'a>(error: &'a (dyn Error + 'static)) -> Option<&'a TraceContext> {
+    core::error::request_ref::<TraceContext>(error)
+}
+
+/// Returns the trace of a converted error context.
+///
+/// Without the `nightly` feature traces can't be requested from arbitrary errors.
+#[cfg(not(feature = "nightly"))]
+fn provided_trace<'a>(error: &'a (dyn Error + 'static)) -> Option<&'a TraceContext> {
+    error.downcast_ref::<TracedError>().map(TracedError::trace)
+}
+
+/// Installs the [`Handler`] as global eyre hook.
+pub fn install() -> core::result::Result<(), ::eyre::InstallError> {
+    ::eyre::set_hook(Box::new(|_| Box::new(Handler)))
+}
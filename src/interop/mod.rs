@@ -0,0 +1,92 @@
+//! Interoperability with other error handling crates.
+//!
+//! - [`anyhow`](https://crates.io/crates/anyhow) with the `anyhow` feature
+//! - [`eyre`](https://crates.io/crates/eyre) with the `eyre` feature
+
+extern crate alloc;
+
+#[cfg(feature = "anyhow")]
+pub mod anyhow;
+#[cfg(feature = "eyre")]
+pub mod eyre;
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use crate::trace::{ErrorMetadata, TraceAccess, TraceContext, Traceable};
+
+/// Object safe combination of the traits required by [`TracedError`].
+trait TraceableContext: Traceable + Send + Sync {}
+
+impl<T> TraceableContext for T where T: Traceable + Send + Sync {}
+
+/// An error context that was converted into a foreign error type
+/// like [`anyhow::Error`](https://docs.rs/anyhow/latest/anyhow/struct.Error.html)
+/// or [`eyre::Report`](https://docs.rs/eyre/latest/eyre/struct.Report.html).
+///
+/// The error displays the message of the inherited error, while the whole trace
+/// is kept alive and can be accessed with [`TracedError::trace`].
+pub struct TracedError {
+    error: Arc<dyn ErrorMetadata>,
+    context: Box<dyn TraceableContext>,
+}
+
+impl TracedError {
+    pub(crate) fn new<T>(context: T) -> Self
+    where
+        T: Traceable + Send + Sync + 'static,
+    {
+        Self {
+            error: context.inner(),
+            context: Box::new(context),
+        }
+    }
+
+    /// Returns the trace of the converted error context.
+    #[inline]
+    pub fn trace(&self) -> &TraceContext {
+        self.context.trace()
+    }
+
+    /// Renders every trace record with the installed [`Formatter`](crate::formatter::Formatter).
+    ///
+    /// The records are ordered from the origin to the top-level error.
+    pub(crate) fn context_lines(&self) -> Vec<String> {
+        self.trace().iter().map(|rec| rec.to_string()).collect()
+    }
+}
+
+impl fmt::Debug for TracedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TracedError")
+            .field("trace", self.trace())
+            .finish()
+    }
+}
+
+impl fmt::Display for TracedError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.display(f)
+    }
+}
+
+impl Error for TracedError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+
+    #[cfg(feature = "nightly")]
+    fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        request.provide_ref::<TraceContext>(self.trace());
+        #[cfg(feature = "backtrace")]
+        if let Some(backtrace) = self.trace().backtrace() {
+            request.provide_ref::<std::backtrace::Backtrace>(backtrace);
+        }
+    }
+}
@@ -6,11 +6,18 @@
     stable_features
 )]
 #![cfg_attr(not(feature = "std"), no_std)]
-#![feature(const_mut_refs)]
-#![feature(error_in_core)]
-#![feature(never_type)]
-#![feature(try_trait_v2)]
-#![feature(try_trait_v2_residual)]
+#![cfg_attr(
+    all(
+        feature = "nightly",
+        any(feature = "anyhow", feature = "backtrace", feature = "eyre")
+    ),
+    feature(error_generic_member_access)
+)]
+#![cfg_attr(feature = "nightly", feature(const_mut_refs))]
+#![cfg_attr(feature = "nightly", feature(error_in_core))]
+#![cfg_attr(feature = "nightly", feature(never_type))]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2))]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2_residual))]
 #![doc = include_str!("lib.md")]
 #![doc(html_root_url = "https://docs.rs/errore/")]
 
@@ -22,40 +29,74 @@ mod aserror;
 mod data;
 mod display;
 mod downcast;
+mod ext;
 mod extensions;
 mod extract;
+mod field;
 pub mod formatter;
 pub mod global;
+#[cfg(feature = "tonic")]
+mod grpc;
 mod hash;
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+pub mod interop;
 mod location;
 mod logging;
+#[cfg(feature = "tower")]
+pub mod middleware;
+#[cfg(feature = "serde")]
+mod problem;
+pub mod registry;
+#[cfg(any(feature = "serde", feature = "tonic"))]
+mod remote;
+mod response;
+#[cfg(feature = "nightly")]
 pub mod result;
+#[cfg(feature = "serde")]
+mod serialize;
 pub mod span;
 pub mod subscriber;
 mod trace;
+#[cfg(any(feature = "serde", feature = "tonic"))]
+mod value;
 
 pub use data::*;
 pub use downcast::Downcasted;
 pub use errore_impl::*;
+pub use ext::ResultExt;
 pub use extensions::{Extension, Extensions, ExtensionsMut};
 pub use extract::{Extract, Extractable};
+pub use field::FieldVisitor;
 pub use location::Location;
-pub use trace::{TraceAccess, TraceContext, TraceContextBuilder, TraceRecord, Traceable};
+#[cfg(feature = "serde")]
+pub use problem::ProblemDetails;
+#[cfg(any(feature = "serde", feature = "tonic"))]
+pub use remote::RemoteError;
+pub use trace::{Classify, TraceAccess, TraceContext, TraceContextBuilder, TraceRecord, Traceable};
 
 pub mod prelude {
-    pub use crate::{
-        result::Result::{self, *},
-        *,
-    };
+    #[cfg(feature = "nightly")]
+    pub use crate::result::Result::{self, *};
+    pub use crate::*;
 }
 
 #[doc(hidden)]
 pub mod __private {
+    #[doc(hidden)]
+    pub extern crate alloc;
     #[doc(hidden)]
     pub use super::access_static_var;
     #[doc(hidden)]
+    pub use super::impl_actix_response;
+    #[doc(hidden)]
+    pub use super::impl_axum_response;
+    #[doc(hidden)]
+    pub use super::impl_descriptor;
+    #[doc(hidden)]
     pub use super::impl_formatter;
     #[doc(hidden)]
+    pub use super::impl_grpc_status;
+    #[doc(hidden)]
     pub use super::impl_static_var;
     #[doc(hidden)]
     pub use super::impl_subscriber;
@@ -64,6 +105,10 @@ pub mod __private {
     #[doc(hidden)]
     pub use crate::display::AsDisplay;
     #[doc(hidden)]
+    pub use crate::display::Redacted;
+    #[doc(hidden)]
+    pub use crate::field::{DebugField, FieldProbe, OpaqueField};
+    #[doc(hidden)]
     pub use crate::global::for_each_subscriber;
     #[doc(hidden)]
     pub use crate::global::get_formatter;
@@ -72,8 +117,26 @@ pub mod __private {
     #[doc(hidden)]
     pub use crate::logging::*;
     #[doc(hidden)]
+    #[cfg(feature = "axum")]
+    pub use crate::response::axum_response;
+    #[doc(hidden)]
+    #[cfg(feature = "actix-web")]
+    pub use crate::response::{actix_response, actix_status};
+    #[doc(hidden)]
     pub use crate::trace::TraceRecordIterator;
     #[doc(hidden)]
+    pub use crate::trace::{
+        ErrorMetadata, MetadataNested, MetadataSeverity, NestedProbe, SeverityProbe, TraceProbe,
+        TraceableNested, TraceableProbe, TraceableType, TypeProbe, UntraceableProbe,
+        UntraceableType, UntracedNested, UntracedSeverity,
+    };
+    #[doc(hidden)]
+    #[cfg(feature = "actix-web")]
+    pub use actix_web;
+    #[doc(hidden)]
+    #[cfg(feature = "axum")]
+    pub use axum;
+    #[doc(hidden)]
     #[cfg(all(not(feature = "std"), not(feature = "ctor")))]
     pub use conquer_once::spin::Lazy;
     #[doc(hidden)]
@@ -90,4 +153,7 @@ pub mod __private {
     #[doc(hidden)]
     #[cfg(feature = "debug-std")]
     pub use log;
+    #[doc(hidden)]
+    #[cfg(feature = "tonic")]
+    pub use tonic;
 }
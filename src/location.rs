@@ -1,28 +1,62 @@
-use core::{fmt, ops::Deref};
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use core::fmt;
 
 /// A struct containing information about the location of the caller.
-#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Location(&'static core::panic::Location<'static>);
+///
+/// Locations captured in this process borrow the file name of [`core::panic::Location`],
+/// while locations of remote errors own it.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Location {
+    file: Cow<'static, str>,
+    line: u32,
+    column: u32,
+}
 
-impl From<&'static core::panic::Location<'static>> for Location {
+impl Location {
+    /// Creates a location from a file name, a line and a column number.
     #[inline]
-    fn from(value: &'static core::panic::Location<'static>) -> Self {
-        Self(value)
+    pub fn new<F>(file: F, line: u32, column: u32) -> Self
+    where
+        F: Into<Cow<'static, str>>,
+    {
+        Self {
+            file: file.into(),
+            line,
+            column,
+        }
     }
-}
 
-impl fmt::Display for Location {
+    /// Returns the name of the source file.
     #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(self.0, f)
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Returns the line number.
+    #[inline]
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Returns the column number.
+    #[inline]
+    pub fn column(&self) -> u32 {
+        self.column
     }
 }
 
-impl Deref for Location {
-    type Target = &'static core::panic::Location<'static>;
+impl From<&'static core::panic::Location<'static>> for Location {
+    #[inline]
+    fn from(value: &'static core::panic::Location<'static>) -> Self {
+        Self::new(value.file(), value.line(), value.column())
+    }
+}
 
+impl fmt::Display for Location {
     #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
     }
 }
@@ -0,0 +1,178 @@
+//! Reporting of service errors with [`tower`](https://crates.io/crates/tower).
+//!
+//! The [`ReportLayer`] wraps a service whose error is an error context.
+//! If the service fails, the method, the URI and the request id of the request
+//! are inserted as [`RequestContext`] into the extensions of the trace context
+//! and [`Subscriber::on_request_error`](crate::subscriber::Subscriber::on_request_error) is called:
+//!
+//! ```ignore
+//! let service = ServiceBuilder::new()
+//!     .layer(ReportLayer::new())
+//!     .service_fn(handle);
+//! ```
+//!
+//! Since the request is also available in [`Subscriber::on_end`](crate::subscriber::Subscriber::on_end),
+//! a report of the error can contain both the trace and the request that caused it:
+//!
+//! ```ignore
+//! fn on_end(&self, ctx: &mut TraceContext) {
+//!     if let Some(req) = ctx.extensions().get::<RequestContext>() {
+//!         warn!("{} {} failed:\n{}", req.method, req.uri, ctx);
+//!     }
+//! }
+//! ```
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use http::header::HeaderName;
+use http::{Method, Request, Uri};
+use pin_project_lite::pin_project;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::extensions::Extension;
+use crate::global::for_each_subscriber;
+use crate::trace::Traceable;
+
+/// Name of the header the request id is read from by default.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Metadata of the request that caused an error.
+///
+/// It is inserted into the extensions of the trace context by [`ReportService`].
+#[derive(Clone, Debug)]
+pub struct RequestContext {
+    /// The HTTP method of the request.
+    pub method: Method,
+    /// The URI of the request.
+    pub uri: Uri,
+    /// The request id, if the request contains the id header.
+    pub request_id: Option<String>,
+}
+
+impl RequestContext {
+    fn new<B>(req: &Request<B>, header: &HeaderName) -> Self {
+        Self {
+            method: req.method().clone(),
+            uri: req.uri().clone(),
+            request_id: req
+                .headers()
+                .get(header)
+                .and_then(|v| v.to_str().ok())
+                .map(String::from),
+        }
+    }
+}
+
+/// A [`Layer`] that reports errors of the inner service with the request that caused them.
+///
+/// See the [module documentation](self) for details.
+#[derive(Clone, Debug)]
+pub struct ReportLayer {
+    header: HeaderName,
+}
+
+impl ReportLayer {
+    /// Creates a layer which reads the request id from the [`x-request-id`](REQUEST_ID_HEADER) header.
+    pub fn new() -> Self {
+        Self {
+            header: HeaderName::from_static(REQUEST_ID_HEADER),
+        }
+    }
+
+    /// Sets the name of the header the request id is read from.
+    pub fn request_id_header(mut self, header: HeaderName) -> Self {
+        self.header = header;
+        self
+    }
+}
+
+impl Default for ReportLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for ReportLayer {
+    type Service = ReportService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ReportService {
+            inner,
+            header: self.header.clone(),
+        }
+    }
+}
+
+/// A service that reports errors of the inner service, see [`ReportLayer`].
+#[derive(Clone, Debug)]
+pub struct ReportService<S> {
+    inner: S,
+    header: HeaderName,
+}
+
+impl<S> ReportService<S> {
+    /// Returns a reference to the inner service.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Consumes the service and returns the inner service.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, B> Service<Request<B>> for ReportService<S>
+where
+    S: Service<Request<B>>,
+    S::Error: Traceable,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let request = RequestContext::new(&req, &self.header);
+        ResponseFuture {
+            inner: self.inner.call(req),
+            request: Some(request),
+        }
+    }
+}
+
+pin_project! {
+    /// Response future of [`ReportService`].
+    pub struct ResponseFuture<F> {
+        #[pin]
+        inner: F,
+        request: Option<RequestContext>,
+    }
+}
+
+impl<F, T, E> Future for ResponseFuture<F>
+where
+    F: Future<Output = Result<T, E>>,
+    E: Traceable,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut result = core::task::ready!(this.inner.poll(cx));
+        if let (Err(error), Some(request)) = (&mut result, this.request.take()) {
+            if let Some(ctx) = error.trace_mut() {
+                ctx.extensions_mut().replace(request.clone());
+                for_each_subscriber(|s| s.on_request_error(ctx, &request));
+            }
+        }
+        Poll::Ready(result)
+    }
+}
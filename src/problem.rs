@@ -0,0 +1,214 @@
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+
+use crate::data::Id;
+use crate::location::Location;
+use crate::registry;
+use crate::trace::{ErrorMetadata, TraceContext, TraceRecord, Traceable};
+use crate::value::Fields;
+
+/// Members defined by RFC 7807, fields of the error with the same name are skipped.
+const RESERVED: &[&str] = &["type", "title", "status", "detail", "instance", "trace"];
+
+/// An error rendered as [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807) problem details document.
+///
+/// The document describes the outermost non-transparent record of the trace:
+///
+/// - `type`: the error code if declared with `#[errore(code = ...)]`, otherwise the error name
+/// - `title`: the format string of the `#[error("...")]` attribute, otherwise the error name
+/// - `status`: the status code of the trace, see [`TraceContext::status()`]
+/// - `detail`: the rendered error message
+/// - `instance`: optional, see [`ProblemDetails::with_instance()`]
+///
+/// Fields of the error are added as extension members, fields marked with `#[redact]` stay masked.
+/// If debug assertions are enabled, all records are included as `trace` member.
+///
+/// ```ignore
+/// let problem = ProblemDetails::new(&ec);
+/// let body = serde_json::to_string(&problem)?;
+/// ```
+///
+/// The title can only be resolved from the [`registry`], which requires the `ctor` feature.
+pub struct ProblemDetails {
+    r#type: String,
+    title: String,
+    status: Option<u16>,
+    detail: Option<String>,
+    instance: Option<String>,
+    extensions: Fields,
+    trace: Option<Vec<ProblemRecord>>,
+}
+
+impl ProblemDetails {
+    /// The media type of a problem details document.
+    pub const CONTENT_TYPE: &'static str = "application/problem+json";
+
+    /// Creates a problem details document from the trace of an error context.
+    ///
+    /// If the trace was already taken, for e.g. by a conversion with `#[from]`,
+    /// the document describes the inherited error without a `trace` member.
+    pub fn new<T>(error: &T) -> Self
+    where
+        T: Traceable + ?Sized,
+    {
+        match error.trace_ref() {
+            Some(ctx) => Self::from(ctx),
+            None => Self::from_error(&*error.inner()),
+        }
+    }
+
+    fn from_error(error: &dyn ErrorMetadata) -> Self {
+        let mut extensions = Fields::default();
+        error.visit_fields(&mut extensions);
+
+        Self {
+            r#type: error.code().unwrap_or(error.name()).to_string(),
+            title: title(error.id(), error.name()),
+            status: error.status(),
+            detail: Some(error.to_string()),
+            instance: None,
+            extensions,
+            trace: None,
+        }
+    }
+
+    /// Sets the `instance` member, for e.g. to the URI of the request.
+    pub fn with_instance<S>(mut self, instance: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    /// Removes the `trace` member, even if debug assertions are enabled.
+    pub fn without_trace(mut self) -> Self {
+        self.trace = None;
+        self
+    }
+
+    /// Returns the `type` member.
+    #[inline]
+    pub fn problem_type(&self) -> &str {
+        &self.r#type
+    }
+
+    /// Returns the `title` member.
+    #[inline]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the `status` member.
+    #[inline]
+    pub fn status(&self) -> Option<u16> {
+        self.status
+    }
+
+    /// Returns the `detail` member.
+    ///
+    /// The detail is `None` if the inherited error was already dropped.
+    #[inline]
+    pub fn detail(&self) -> Option<&str> {
+        self.detail.as_deref()
+    }
+
+    /// Returns the `instance` member.
+    #[inline]
+    pub fn instance(&self) -> Option<&str> {
+        self.instance.as_deref()
+    }
+}
+
+impl From<&TraceContext> for ProblemDetails {
+    fn from(ctx: &TraceContext) -> Self {
+        let rec = ctx
+            .trace
+            .iter()
+            .rev()
+            .find(|r| !r.is_transparent)
+            .unwrap_or_else(|| ctx.last());
+
+        let mut extensions = Fields::default();
+        rec.visit_fields(&mut extensions);
+
+        let trace =
+            cfg!(debug_assertions).then(|| ctx.trace.iter().map(ProblemRecord::from).collect());
+
+        Self {
+            r#type: rec.code.as_ref().unwrap_or(&rec.name).to_string(),
+            title: title(&rec.id, &rec.name),
+            status: ctx.status(),
+            detail: message(rec),
+            instance: None,
+            extensions,
+            trace,
+        }
+    }
+}
+
+/// Serializes the document as JSON object, extension members follow the members of RFC 7807.
+impl Serialize for ProblemDetails {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &self.r#type)?;
+        map.serialize_entry("title", &self.title)?;
+        if let Some(status) = &self.status {
+            map.serialize_entry("status", status)?;
+        }
+        if let Some(detail) = &self.detail {
+            map.serialize_entry("detail", detail)?;
+        }
+        if let Some(instance) = &self.instance {
+            map.serialize_entry("instance", instance)?;
+        }
+        for (name, value) in self.extensions.iter() {
+            if !RESERVED.contains(&name) {
+                map.serialize_entry(name, value)?;
+            }
+        }
+        if let Some(trace) = &self.trace {
+            map.serialize_entry("trace", trace)?;
+        }
+        map.end()
+    }
+}
+
+/// A record of the debug-only `trace` member.
+#[derive(Serialize)]
+struct ProblemRecord {
+    name: Cow<'static, str>,
+    code: Option<Cow<'static, str>>,
+    location: Location,
+    message: Option<String>,
+}
+
+impl From<&TraceRecord> for ProblemRecord {
+    fn from(rec: &TraceRecord) -> Self {
+        Self {
+            name: rec.name.clone(),
+            code: rec.code.clone(),
+            location: rec.location.clone(),
+            message: message(rec),
+        }
+    }
+}
+
+/// Resolves the format string of the error from the [`registry`], otherwise the error name.
+fn title(id: &Id, name: &str) -> String {
+    registry::get(id)
+        .and_then(|descriptor| descriptor.display())
+        .map_or_else(|| name.to_string(), String::from)
+}
+
+/// Renders the message of the inherited error without the span format.
+fn message(rec: &TraceRecord) -> Option<String> {
+    rec.error_ref()
+        .map(|error| TraceRecord::without_span_format(&rec.format_span, || error.to_string()))
+}
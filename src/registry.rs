@@ -0,0 +1,180 @@
+//! Catalog of all errors, that were derived with `errore::Error` and are linked into the binary.
+//!
+//! Every enum variant and every struct registers one [`Descriptor`].
+//! The registry can be used to check the uniqueness of error identifiers at startup
+//! or to list all errors of an application:
+//!
+//! ```ignore
+//! for descriptor in errore::registry::iter() {
+//!     println!("{} [{}]", descriptor.name(), descriptor.id());
+//! }
+//! ```
+//!
+//! <div class="warning">
+//! The registry relies on link sections and is only populated if the `ctor` feature is enabled.
+//! Otherwise the iterator is always empty.
+//! </div>
+
+use core::fmt;
+
+use crate::data::{Id, Severity};
+
+/// Describes an error type that was derived with `errore::Error`.
+///
+/// For enums a descriptor exists for every variant.
+pub struct Descriptor {
+    name: fn() -> &'static str,
+    id: fn() -> &'static Id,
+    target: fn() -> &'static str,
+    target_id: fn() -> &'static Id,
+    display: Option<&'static str>,
+    doc: &'static str,
+    from: Option<&'static str>,
+    is_transparent: bool,
+    code: Option<&'static str>,
+    severity: Severity,
+}
+
+impl Descriptor {
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        name: fn() -> &'static str,
+        id: fn() -> &'static Id,
+        target: fn() -> &'static str,
+        target_id: fn() -> &'static Id,
+        display: Option<&'static str>,
+        doc: &'static str,
+        from: Option<&'static str>,
+        is_transparent: bool,
+        code: Option<&'static str>,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            name,
+            id,
+            target,
+            target_id,
+            display,
+            doc,
+            from,
+            is_transparent,
+            code,
+            severity,
+        }
+    }
+
+    /// Returns the name of the error.
+    ///
+    /// See [`Metadata::name()`](crate::Metadata::name).
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        (self.name)()
+    }
+
+    /// Returns the identifier of the error.
+    ///
+    /// See [`Metadata::id()`](crate::Metadata::id).
+    #[inline]
+    pub fn id(&self) -> &'static Id {
+        (self.id)()
+    }
+
+    /// Returns the crate name in which the error was declared.
+    ///
+    /// See [`Metadata::target()`](crate::Metadata::target).
+    #[inline]
+    pub fn target(&self) -> &'static str {
+        (self.target)()
+    }
+
+    /// Returns the identifier of the crate in which the error was declared.
+    ///
+    /// See [`Metadata::target_id()`](crate::Metadata::target_id).
+    #[inline]
+    pub fn target_id(&self) -> &'static Id {
+        (self.target_id)()
+    }
+
+    /// Returns the format string of the `#[error("...")]` attribute as written in the source code.
+    ///
+    /// Transparent errors don't have a format string.
+    #[inline]
+    pub fn display(&self) -> Option<&'static str> {
+        self.display
+    }
+
+    /// Returns the doc comment of the error or an empty string if it isn't documented.
+    #[inline]
+    pub fn doc(&self) -> &'static str {
+        self.doc
+    }
+
+    /// Returns the source type of the `#[from]` attribute.
+    #[inline]
+    pub fn from(&self) -> Option<&'static str> {
+        self.from
+    }
+
+    /// Returns whether the error is declared with `#[error(transparent)]`.
+    #[inline]
+    pub fn is_transparent(&self) -> bool {
+        self.is_transparent
+    }
+
+    /// Returns the stable error code.
+    ///
+    /// See [`Metadata::code()`](crate::Metadata::code).
+    #[inline]
+    pub fn code(&self) -> Option<&'static str> {
+        self.code
+    }
+
+    /// Returns the severity of the error.
+    ///
+    /// See [`Metadata::severity()`](crate::Metadata::severity).
+    #[inline]
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+}
+
+impl fmt::Debug for Descriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Descriptor")
+            .field("name", &self.name())
+            .field("id", self.id())
+            .field("target", &self.target())
+            .field("target_id", self.target_id())
+            .field("display", &self.display)
+            .field("doc", &self.doc)
+            .field("from", &self.from)
+            .field("is_transparent", &self.is_transparent)
+            .field("code", &self.code)
+            .field("severity", &self.severity)
+            .finish()
+    }
+}
+
+#[cfg(all(feature = "ctor", not(miri)))]
+inventory::collect!(Descriptor);
+
+/// Returns an iterator over all registered error descriptors.
+#[cfg(all(feature = "ctor", not(miri)))]
+#[inline]
+pub fn iter() -> impl Iterator<Item = &'static Descriptor> {
+    inventory::iter::<Descriptor>.into_iter()
+}
+
+/// Returns an iterator over all registered error descriptors.
+#[cfg(any(not(feature = "ctor"), miri))]
+#[inline]
+pub fn iter() -> impl Iterator<Item = &'static Descriptor> {
+    core::iter::empty()
+}
+
+/// Returns the descriptor of the error with the given identifier.
+#[inline]
+pub fn get(id: &Id) -> Option<&'static Descriptor> {
+    iter().find(|descriptor| descriptor.id() == id)
+}
@@ -0,0 +1,317 @@
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::sync::{Arc, Weak};
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer};
+
+use crate::data::{Classification, Id, Metadata, Severity};
+use crate::field::FieldVisitor;
+use crate::global::for_each_subscriber;
+use crate::location::Location;
+use crate::trace::{ErrorMetadata, TraceContext, TraceContextBuilder, TraceRecord, Traceable};
+use crate::value::Fields;
+
+static REMOTE_ID: Id = Id::from_target("errore::RemoteError");
+static REMOTE_TARGET_ID: Id = Id::from_target("errore");
+
+/// An error reconstructed from a trace report of another process.
+///
+/// The report is the serialized [`TraceContext`] of the `serde` feature.
+/// Once deserialized, the error can be converted into a local error context with `#[from]`:
+///
+/// ```ignore
+/// #[derive(Error, Debug)]
+/// pub enum Error {
+///     #[error(transparent)]
+///     Remote(#[from] RemoteError),
+/// }
+///
+/// let remote: RemoteError = serde_json::from_str(&response)?;
+/// Err(Ec::from(remote))
+/// ```
+///
+/// With the `tonic` feature, the error can also be converted from a [`tonic::Status`]
+/// that was created from an error context, see [`Metadata::grpc_code()`].
+///
+/// The remote records are prepended to the local trace context,
+/// so the trace shows both the remote and the local hops.
+/// Messages, help, notes and fields of remote records are kept as they were rendered by the remote.
+pub struct RemoteError {
+    ctx: Option<TraceContext>,
+    records: Vec<Arc<RemoteRecord>>,
+}
+
+impl RemoteError {
+    fn last(&self) -> &Arc<RemoteRecord> {
+        self.records
+            .last()
+            .expect("Remote error must have at least one or more records. Please open an issue.")
+    }
+}
+
+impl fmt::Debug for RemoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteError")
+            .field("ctx", &self.ctx)
+            .field("message", &self.last().message)
+            .finish()
+    }
+}
+
+impl fmt::Display for RemoteError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self.last(), f)
+    }
+}
+
+impl Error for RemoteError {}
+
+impl Traceable for RemoteError {
+    #[inline]
+    fn trace(&self) -> &TraceContext {
+        self.ctx
+            .as_ref()
+            .expect("Trace should be available in 'Traceable::trace'")
+    }
+
+    #[inline]
+    fn trace_ref(&self) -> Option<&TraceContext> {
+        self.ctx.as_ref()
+    }
+
+    #[inline]
+    fn trace_mut(&mut self) -> Option<&mut TraceContext> {
+        self.ctx.as_mut()
+    }
+
+    #[inline]
+    fn take_trace(&mut self) -> Option<TraceContext> {
+        self.ctx.take()
+    }
+
+    #[inline]
+    fn inner(&self) -> Arc<dyn ErrorMetadata> {
+        self.last().clone()
+    }
+
+    fn insert(&mut self, record: TraceRecord) -> bool {
+        self.ctx
+            .as_mut()
+            .expect("Trace should be available in 'Traceable::insert'")
+            .insert(record)
+    }
+}
+
+impl RemoteError {
+    /// Rebuilds the trace context from the records of a report.
+    ///
+    /// Returns `None` if there are no records.
+    pub(crate) fn from_records(report: Vec<Record>) -> Option<Self> {
+        if report.is_empty() {
+            return None;
+        }
+
+        let mut builder = TraceContextBuilder::new();
+        builder.reserve_trace(report.len());
+
+        let mut records = Vec::with_capacity(report.len());
+        let mut trace = Vec::with_capacity(report.len());
+        for rec in report {
+            let error = Arc::new(RemoteRecord {
+                severity: rec.severity,
+                classification: rec.classification,
+                status: rec.status,
+                message: rec.message,
+                help: rec.help,
+                note: rec.note,
+                fields: rec.fields,
+                nested: rec.nested,
+            });
+            let inner: Weak<dyn ErrorMetadata> = Arc::downgrade(&error) as _;
+            trace.push(TraceRecord {
+                location: rec.location,
+                name: Cow::Owned(rec.name),
+                target: Cow::Owned(rec.target),
+                target_id: rec.target_id,
+                id: rec.id,
+                is_transparent: rec.is_transparent,
+                code: rec.code.map(Cow::Owned),
+                severity: rec.severity,
+                classification: rec.classification,
+                status: rec.status,
+                grpc_code: rec.grpc_code.map(Cow::Owned),
+                context: rec.context.map(Cow::Owned),
+                attachments: Vec::new(),
+                inner: Some(inner),
+                format_span: builder.format_span.clone(),
+            });
+            records.push(error);
+        }
+
+        // The trace continues in this process, so subscribers are able to prepare their data.
+        for_each_subscriber(|s| s.on_start(&mut builder, &trace[0]));
+
+        let mut ctx = builder.build();
+        for rec in trace {
+            ctx.insert(rec);
+        }
+
+        Some(Self {
+            ctx: Some(ctx),
+            records,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for RemoteError {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let report = Report::deserialize(deserializer)?;
+        RemoteError::from_records(report.records)
+            .ok_or_else(|| de::Error::invalid_length(0, &"at least one record"))
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct Report {
+    records: Vec<Record>,
+}
+
+/// A record of a remote trace, see [`RemoteError::from_records()`].
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+pub(crate) struct Record {
+    pub(crate) name: String,
+    pub(crate) id: Id,
+    pub(crate) target: String,
+    pub(crate) target_id: Id,
+    pub(crate) location: Location,
+    pub(crate) is_transparent: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) code: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) severity: Severity,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) classification: Classification,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) status: Option<u16>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) grpc_code: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) message: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) context: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) help: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) note: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) fields: Option<Fields>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) nested: Vec<RemoteError>,
+}
+
+/// The inherited error of a remote trace record.
+struct RemoteRecord {
+    severity: Severity,
+    classification: Classification,
+    status: Option<u16>,
+    message: Option<String>,
+    help: Option<String>,
+    note: Option<String>,
+    fields: Option<Fields>,
+    nested: Vec<RemoteError>,
+}
+
+impl fmt::Debug for RemoteRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteRecord")
+            .field("severity", &self.severity)
+            .field("message", &self.message)
+            .finish()
+    }
+}
+
+impl fmt::Display for RemoteRecord {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message.as_deref().unwrap_or_default())
+    }
+}
+
+impl Error for RemoteRecord {}
+
+impl Metadata for RemoteRecord {
+    fn name(&self) -> &'static str {
+        "errore::RemoteError"
+    }
+
+    fn id(&self) -> &'static Id {
+        &REMOTE_ID
+    }
+
+    fn target(&self) -> &'static str {
+        "errore"
+    }
+
+    fn target_id(&self) -> &'static Id {
+        &REMOTE_TARGET_ID
+    }
+
+    fn display(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+
+    fn is_transparent(&self) -> bool {
+        false
+    }
+
+    fn code(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn classification(&self) -> Classification {
+        self.classification
+    }
+
+    fn status(&self) -> Option<u16> {
+        self.status
+    }
+
+    fn grpc_code(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn help(&self) -> Option<String> {
+        self.help.clone()
+    }
+
+    fn note(&self) -> Option<String> {
+        self.note.clone()
+    }
+
+    fn visit_fields(&self, visitor: &mut dyn FieldVisitor) {
+        if let Some(fields) = &self.fields {
+            fields.visit(visitor);
+        }
+    }
+
+    fn visit_nested(&self, visitor: &mut dyn FnMut(&TraceContext)) {
+        for error in &self.nested {
+            if let Some(ctx) = error.trace_ref() {
+                visitor(ctx);
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Conversions of error contexts into HTTP responses.
+//!
+//! The status code is resolved with [`TraceContext::status()`](crate::TraceContext::status)
+//! and the body is rendered with [`Formatter::format_response()`](crate::formatter::Formatter::format_response).
+//!
+//! The derive macro passes the implementations to `impl_axum_response!`, `impl_actix_response!`
+//! and `impl_grpc_status!` for the conversion into [`tonic::Status`](https://docs.rs/tonic/latest/tonic/struct.Status.html),
+//! which only expand them if the respective feature of this crate is enabled.
+
+#[cfg(feature = "actix-web")]
+use actix_web::{http::header::ContentType, http::StatusCode, HttpResponse};
+
+#[cfg(feature = "actix-web")]
+#[inline]
+pub fn actix_status(status: u16) -> StatusCode {
+    StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+#[cfg(feature = "actix-web")]
+pub fn actix_response(status: u16, body: String) -> HttpResponse {
+    HttpResponse::build(actix_status(status))
+        .content_type(ContentType::plaintext())
+        .body(body)
+}
+
+#[cfg(feature = "axum")]
+pub fn axum_response(status: u16, body: String) -> axum::response::Response {
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+
+    let status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    (status, body).into_response()
+}
+
+#[cfg(feature = "axum")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_axum_response {
+    ($($impl:tt)*) => {
+        $($impl)*
+    };
+}
+
+#[cfg(not(feature = "axum"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_axum_response {
+    ($($impl:tt)*) => {};
+}
+
+#[cfg(feature = "actix-web")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_actix_response {
+    ($($impl:tt)*) => {
+        $($impl)*
+    };
+}
+
+#[cfg(not(feature = "actix-web"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_actix_response {
+    ($($impl:tt)*) => {};
+}
+
+#[cfg(feature = "tonic")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_grpc_status {
+    ($($impl:tt)*) => {
+        $($impl)*
+    };
+}
+
+#[cfg(not(feature = "tonic"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_grpc_status {
+    ($($impl:tt)*) => {};
+}
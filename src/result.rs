@@ -1,5 +1,7 @@
 extern crate alloc;
 
+use alloc::borrow::Cow;
+use core::any::Any;
 use core::convert;
 use core::convert::Infallible;
 use core::error::Error;
@@ -15,16 +17,9 @@ use core::ops::Try;
 
 use crate::data::Metadata;
 use crate::dlog;
+use crate::ext::insert_context;
 use crate::trace::{TraceRecord, Traceable};
 
-/// Shorthand macro for [`Result::Err`] in order to create an error context from a related enum or struct type.
-#[macro_export]
-macro_rules! err {
-    ($enum_error:expr) => {
-        Err(Ec::new($enum_error))
-    };
-}
-
 /// `Result` is a type that represents either success ([`Ok`](Result::Ok)) or failure ([`Err`](Result::Err)).
 ///
 /// On Failure it additionally records the error for later retrieval.
@@ -38,7 +33,6 @@ pub enum Result<T, E> {
 
 impl<T, E, F> FromResidual<core::result::Result<Infallible, E>> for Result<T, F>
 where
-    E: fmt::Display + Error,
     F: From<E> + Error + Traceable + Metadata,
 {
     #[track_caller]
@@ -108,7 +102,7 @@ where
                     e.trace_ref()
                         .expect("Trace must be available in 'FromResidual'"),
                 );
-                rec.name = core::any::type_name::<F>();
+                rec.name = core::any::type_name::<F>().into();
                 dlog!(
                     "insert ({}) to ({})\n\tin {}",
                     e.name(),
@@ -1096,6 +1090,114 @@ impl<T, E> Result<T, E> {
     }
 }
 
+impl<T, E> Result<T, E>
+where
+    E: Error + Traceable + Metadata,
+{
+    /// Attaches a message to the trace of the error, without declaring a new error type.
+    ///
+    /// If [`Err`], a record with the message and the location of the caller
+    /// is appended to the trace and rendered below the current node:
+    ///
+    /// ```ignore
+    /// fn load() -> Result<Config, Ec> {
+    ///     let config = read("config.toml").context("loading config")?;
+    ///     Ok(config)
+    /// }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn context<C>(self, context: C) -> Self
+    where
+        C: Into<Cow<'static, str>>,
+    {
+        match self {
+            Self::Ok(t) => Self::Ok(t),
+            Self::Err(mut e) => {
+                insert_context(&mut e, context.into());
+                Self::Err(e)
+            }
+        }
+    }
+
+    /// Attaches a message to the trace of the error, see [`Result::context()`].
+    ///
+    /// The message is only created if [`Err`]:
+    ///
+    /// ```ignore
+    /// read(&path).with_context(|| format!("loading {}", path))?;
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn with_context<C, F>(self, f: F) -> Self
+    where
+        C: Into<Cow<'static, str>>,
+        F: FnOnce() -> C,
+    {
+        match self {
+            Self::Ok(t) => Self::Ok(t),
+            Self::Err(mut e) => {
+                insert_context(&mut e, f().into());
+                Self::Err(e)
+            }
+        }
+    }
+}
+
+impl<T, E> Result<T, E>
+where
+    E: Traceable,
+{
+    /// Attaches a value to the current record of the trace, for e.g. the query or the retry attempt.
+    ///
+    /// Multiple values of the same type can be attached at different propagation sites
+    /// and are returned in propagation order by `attachments::<T>()`:
+    ///
+    /// ```ignore
+    /// let rows = query(&sql).attach(sql.clone())?;
+    ///
+    /// // at the caller
+    /// let queries: Vec<&String> = ec.attachments::<String>().collect();
+    /// ```
+    ///
+    /// Attachments are kept in this process and are not serialized.
+    #[inline]
+    pub fn attach<A>(self, value: A) -> Self
+    where
+        A: Any + Send + Sync,
+    {
+        match self {
+            Self::Ok(t) => Self::Ok(t),
+            Self::Err(mut e) => {
+                if let Some(ctx) = e.trace_mut() {
+                    ctx.attach(value);
+                }
+                Self::Err(e)
+            }
+        }
+    }
+
+    /// Attaches a value to the current record of the trace, see [`Result::attach()`].
+    ///
+    /// The value is only created if [`Err`].
+    #[inline]
+    pub fn attach_with<A, F>(self, f: F) -> Self
+    where
+        A: Any + Send + Sync,
+        F: FnOnce() -> A,
+    {
+        match self {
+            Self::Ok(t) => Self::Ok(t),
+            Self::Err(mut e) => {
+                if let Some(ctx) = e.trace_mut() {
+                    ctx.attach(f());
+                }
+                Self::Err(e)
+            }
+        }
+    }
+}
+
 impl<T, E> Result<&T, E> {
     /// Maps a `Result<&T, E>` to a `Result<T, E>` by copying the contents of the
     /// `Ok` part.
@@ -1134,10 +1236,7 @@ impl<T, E> Result<&T, E> {
     where
         T: Clone,
     {
-        self.map(|t| {
-            let g = t.clone();
-            g
-        })
+        self.map(|t| t.clone())
     }
 }
 
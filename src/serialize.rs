@@ -0,0 +1,268 @@
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::fmt;
+
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::{Error as _, SerializeMap, SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::data::{Classification, Id, Severity};
+use crate::location::Location;
+use crate::trace::{TraceContext, TraceRecord};
+use crate::value::{Fields, Value};
+
+/// Serializes the hash as unsigned 64-bit integer.
+impl Serialize for Id {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(**self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Id {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Id::from)
+    }
+}
+
+/// Serializes the severity as lowercase string, see [`Severity::as_str()`].
+impl Serialize for Severity {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Serializes the classification as lowercase string, see [`Classification::as_str()`].
+impl Serialize for Classification {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Classification {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[&str] = &["unclassified", "transient", "permanent"];
+        let classification = String::deserialize(deserializer)?;
+        match classification.as_str() {
+            "unclassified" => Ok(Classification::Unclassified),
+            "transient" => Ok(Classification::Transient),
+            "permanent" => Ok(Classification::Permanent),
+            other => Err(de::Error::unknown_variant(other, VARIANTS)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Severity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[&str] = &["warn", "error", "fatal"];
+        let severity = String::deserialize(deserializer)?;
+        match severity.as_str() {
+            "warn" => Ok(Severity::Warn),
+            "error" => Ok(Severity::Error),
+            "fatal" => Ok(Severity::Fatal),
+            other => Err(de::Error::unknown_variant(other, VARIANTS)),
+        }
+    }
+}
+
+/// Serializes the location as `{ "file", "line", "column" }`.
+impl Serialize for Location {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Location", 3)?;
+        s.serialize_field("file", self.file())?;
+        s.serialize_field("line", &self.line())?;
+        s.serialize_field("column", &self.column())?;
+        s.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Location {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Owned {
+            file: String,
+            line: u32,
+            column: u32,
+        }
+
+        let location = Owned::deserialize(deserializer)?;
+        Ok(Location::new(location.file, location.line, location.column))
+    }
+}
+
+/// Serializes the trace context as `{ "records": [...] }`,
+/// starting with the origin error.
+impl Serialize for TraceContext {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("TraceContext", 1)?;
+        s.serialize_field("records", &Records(self))?;
+        s.end()
+    }
+}
+
+/// Serializes the trace record with the rendered message and the fields of the inherited error.
+///
+/// If the inherited error was already dropped,
+/// `message`, `help`, `note` and `fields` are serialized as `null`.
+impl Serialize for TraceRecord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let error = self.error_ref();
+        // Render nested spans with their message only, like the formatter does.
+        let message = error
+            .as_ref()
+            .map(|e| TraceRecord::without_span_format(&self.format_span, || e.to_string()));
+        let fields = error.as_ref().map(|_| {
+            let mut fields = Fields::default();
+            self.visit_fields(&mut fields);
+            fields
+        });
+
+        let mut s = serializer.serialize_struct("TraceRecord", 17)?;
+        s.serialize_field("name", &self.name)?;
+        s.serialize_field("id", &self.id)?;
+        s.serialize_field("target", &self.target)?;
+        s.serialize_field("target_id", &self.target_id)?;
+        s.serialize_field("location", &self.location)?;
+        s.serialize_field("is_transparent", &self.is_transparent)?;
+        s.serialize_field("code", &self.code)?;
+        s.serialize_field("severity", &self.severity)?;
+        s.serialize_field("classification", &self.classification)?;
+        s.serialize_field("status", &self.status)?;
+        s.serialize_field("grpc_code", &self.grpc_code)?;
+        s.serialize_field("message", &message)?;
+        s.serialize_field("context", &self.context)?;
+        s.serialize_field("help", &self.help())?;
+        s.serialize_field("note", &self.note())?;
+        s.serialize_field("fields", &fields)?;
+        s.serialize_field("nested", &Nested(self))?;
+        s.end()
+    }
+}
+
+struct Records<'a>(&'a TraceContext);
+
+impl<'a> Serialize for Records<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.trace.len()))?;
+        for record in &self.0.trace {
+            seq.serialize_element(record)?;
+        }
+        seq.end()
+    }
+}
+
+/// Serializes the trace contexts of errors nested in fields of the inherited error.
+struct Nested<'a>(&'a TraceRecord);
+
+impl<'a> Serialize for Nested<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let seq = Cell::new(Some(serializer.serialize_seq(None)?));
+        let error = Cell::new(None);
+        self.0.visit_nested(|ctx| {
+            if let Some(mut s) = seq.take() {
+                match s.serialize_element(ctx) {
+                    Ok(()) => seq.set(Some(s)),
+                    Err(e) => error.set(Some(e)),
+                }
+            }
+        });
+        match (seq.into_inner(), error.into_inner()) {
+            (_, Some(e)) => Err(e),
+            (Some(s), None) => s.end(),
+            (None, None) => Err(S::Error::custom("nested sequence was not finished")),
+        }
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Str(v) => serializer.serialize_str(v),
+            Value::Bool(v) => serializer.serialize_bool(*v),
+            Value::I64(v) => serializer.serialize_i64(*v),
+            Value::U64(v) => serializer.serialize_u64(*v),
+            Value::F64(v) => serializer.serialize_f64(*v),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ValueVisitor;
+
+        impl<'de> Visitor<'de> for ValueVisitor {
+            type Value = Value;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string, boolean or number")
+            }
+
+            fn visit_bool<E: de::Error>(self, v: bool) -> Result<Value, E> {
+                Ok(Value::Bool(v))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Value, E> {
+                Ok(Value::I64(v))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Value, E> {
+                Ok(Value::U64(v))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Value, E> {
+                Ok(Value::F64(v))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Value, E> {
+                Ok(Value::Str(v.to_string()))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Value, E> {
+                Ok(Value::Str(v))
+            }
+        }
+
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+impl Serialize for Fields {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, value) in &self.0 {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Fields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldsVisitor;
+
+        impl<'de> Visitor<'de> for FieldsVisitor {
+            type Value = Fields;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map of fields")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Fields, A::Error> {
+                // Keep the order of the fields like they were declared.
+                let mut fields = Vec::with_capacity(map.size_hint().unwrap_or(0));
+                while let Some(entry) = map.next_entry()? {
+                    fields.push(entry);
+                }
+                Ok(Fields(fields))
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor)
+    }
+}
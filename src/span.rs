@@ -1,17 +1,20 @@
 extern crate alloc;
 
-use alloc::string::ToString;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::error::Error;
 use core::marker::{Send, Sync};
 use core::sync::atomic::AtomicBool;
 use core::{fmt, sync::atomic::Ordering};
 
-use crate::data::{Id, Metadata};
+use crate::data::{Classification, Id, Metadata, Severity};
 use crate::dlog;
 use crate::downcast::Downcasted;
 use crate::extensions::{Extension, Extensions, ExtensionsMut};
 use crate::extract::{Extract, Extractable};
+use crate::field::FieldVisitor;
 use crate::global::{for_each_subscriber, get_formatter};
 use crate::trace::{
     TraceAccess, TraceContext, TraceContextBuilder, TraceRecord, TraceRecordIterator,
@@ -72,7 +75,7 @@ impl<'a> TraceAccess for SpanContext<'a> {
     }
 
     #[inline]
-    fn iter(&self) -> TraceRecordIterator {
+    fn iter(&self) -> TraceRecordIterator<'_> {
         self.ctx.iter()
     }
 }
@@ -123,6 +126,8 @@ where
             .field("target_id", &self.record.target_id)
             .field("id", &self.record.id)
             .field("is_transparent", &self.record.is_transparent)
+            .field("code", &self.record.code)
+            .field("severity", &self.record.severity)
             .finish()
     }
 }
@@ -164,15 +169,23 @@ where
         let insert;
         let (mut ctx, record) = match ctx {
             Some(v) => {
-                insert = false;
+                // Without the nightly `Result`, conversions with `?` are only recorded here.
+                insert = cfg!(not(feature = "nightly"));
 
                 let record = TraceRecord {
                     location: core::panic::Location::caller().into(),
-                    name: inner_owned.name(),
-                    target: inner_owned.target(),
+                    name: inner_owned.name().into(),
+                    target: inner_owned.target().into(),
                     target_id: *inner_owned.target_id(),
                     id: *inner_owned.id(),
                     is_transparent: inner_owned.is_transparent(),
+                    code: inner_owned.code().map(Cow::Borrowed),
+                    severity: inner_owned.severity(),
+                    classification: inner_owned.classification(),
+                    status: inner_owned.status(),
+                    grpc_code: inner_owned.grpc_code().map(Cow::Borrowed),
+                    context: None,
+                    attachments: Vec::new(),
                     inner: Some(inner_ref),
                     format_span: v.format_span.clone(),
                 };
@@ -187,13 +200,24 @@ where
                 // Instead an IndexSet is used for the trace to only allow unique items.
                 insert = true;
                 let mut builder = TraceContextBuilder::new();
+                #[cfg(feature = "backtrace")]
+                if inner_owned.has_backtrace() {
+                    builder.backtrace = Some(std::backtrace::Backtrace::capture());
+                }
                 let record = TraceRecord {
                     location: core::panic::Location::caller().into(),
-                    name: inner_owned.name(),
-                    target: inner_owned.target(),
+                    name: inner_owned.name().into(),
+                    target: inner_owned.target().into(),
                     target_id: *inner_owned.target_id(),
                     id: *inner_owned.id(),
                     is_transparent: inner_owned.is_transparent(),
+                    code: inner_owned.code().map(Cow::Borrowed),
+                    severity: inner_owned.severity(),
+                    classification: inner_owned.classification(),
+                    status: inner_owned.status(),
+                    grpc_code: inner_owned.grpc_code().map(Cow::Borrowed),
+                    context: None,
+                    attachments: Vec::new(),
                     inner: Some(inner_ref),
                     format_span: builder.format_span.clone(),
                 };
@@ -233,6 +257,45 @@ where
     }
 }
 
+impl<T> Span<T>
+where
+    T: Error + Metadata,
+{
+    /// Moves the error and the trace context out of the span.
+    ///
+    /// The span is returned unchanged if the error is still shared, for e.g. by a [`Downcasted`],
+    /// or if the trace context was already moved to another span.
+    #[doc(hidden)]
+    #[allow(clippy::result_large_err)]
+    pub fn into_parts(mut self) -> Result<(T, TraceContext), Self> {
+        let Some(ctx) = self.ctx.take() else {
+            return Err(self);
+        };
+
+        // The span implements Drop, so its fields can't be moved out.
+        // Without a trace context, dropping the span doesn't call the subscribers
+        // and only releases its reference to the error.
+        let format_span = self.format_span.clone();
+        let record = self.record.clone();
+        let inner = self.inner.clone();
+        drop(self);
+
+        match Arc::try_unwrap(inner) {
+            Ok(inner) => {
+                // The trace context will call the subscribers on drop instead.
+                format_span.store(true, Ordering::Relaxed);
+                Ok((inner, ctx))
+            }
+            Err(inner) => Err(Self {
+                format_span,
+                ctx: Some(ctx),
+                record,
+                inner,
+            }),
+        }
+    }
+}
+
 impl<'a, T> IntoIterator for &'a Span<T>
 where
     T: Error + Metadata + 'static,
@@ -283,6 +346,56 @@ where
     fn is_transparent(&self) -> bool {
         self.inner.is_transparent()
     }
+
+    #[inline]
+    fn code(&self) -> Option<&'static str> {
+        self.inner.code()
+    }
+
+    #[inline]
+    fn severity(&self) -> Severity {
+        self.inner.severity()
+    }
+
+    #[inline]
+    fn classification(&self) -> Classification {
+        self.inner.classification()
+    }
+
+    #[inline]
+    fn status(&self) -> Option<u16> {
+        self.inner.status()
+    }
+
+    #[inline]
+    fn grpc_code(&self) -> Option<&'static str> {
+        self.inner.grpc_code()
+    }
+
+    #[inline]
+    fn help(&self) -> Option<String> {
+        self.inner.help()
+    }
+
+    #[inline]
+    fn note(&self) -> Option<String> {
+        self.inner.note()
+    }
+
+    #[inline]
+    fn visit_fields(&self, visitor: &mut dyn FieldVisitor) {
+        self.inner.visit_fields(visitor)
+    }
+
+    #[inline]
+    fn visit_nested(&self, visitor: &mut dyn FnMut(&TraceContext)) {
+        self.inner.visit_nested(visitor)
+    }
+
+    #[inline]
+    fn has_backtrace(&self) -> bool {
+        self.inner.has_backtrace()
+    }
 }
 
 impl<T> fmt::Display for Span<T>
@@ -315,6 +428,45 @@ where
     }
 }
 
+impl<T> Span<T>
+where
+    T: Error + Metadata + 'static,
+{
+    /// Returns the HTTP status code of the error chain, see [`TraceContext::status()`].
+    ///
+    /// Defaults to `500` if no error declares a status.
+    #[doc(hidden)]
+    pub fn status_code(&self) -> u16 {
+        self.ctx
+            .as_ref()
+            .map_or_else(|| self.status(), TraceContext::status)
+            .unwrap_or(500)
+    }
+
+    /// Renders the body of an HTTP response with [`Formatter::format_response`](crate::formatter::Formatter::format_response).
+    #[doc(hidden)]
+    pub fn format_response(&self) -> String {
+        struct Body<'a, T>(&'a Span<T>)
+        where
+            T: Error + Metadata + 'static;
+
+        impl<'a, T> fmt::Display for Body<'a, T>
+        where
+            T: Error + Metadata + 'static,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match &self.0.ctx {
+                    Some(ctx) => get_formatter().format_response(self.0, ctx, f),
+                    None => self.0.display(f),
+                }
+            }
+        }
+
+        // Nested spans are rendered with their message only.
+        TraceRecord::without_span_format(&self.format_span, || Body(self).to_string())
+    }
+}
+
 impl<T> Extract for Span<T>
 where
     T: Error + Metadata + 'static,
@@ -336,4 +488,22 @@ where
     }
 }
 
-impl<T> Error for Span<T> where T: Error + Metadata + 'static {}
+impl<T> Error for Span<T>
+where
+    T: Error + Metadata + 'static,
+{
+    #[cfg(all(
+        feature = "nightly",
+        any(feature = "anyhow", feature = "backtrace", feature = "eyre")
+    ))]
+    fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        if let Some(ctx) = &self.ctx {
+            request.provide_ref::<TraceContext>(ctx);
+            #[cfg(feature = "backtrace")]
+            if let Some(backtrace) = ctx.backtrace() {
+                request.provide_ref::<std::backtrace::Backtrace>(backtrace);
+            }
+        }
+        self.inner.provide(request);
+    }
+}
@@ -1,6 +1,8 @@
 use core::fmt::Debug;
 use core::marker::{Send, Sync};
 
+#[cfg(feature = "tower")]
+use crate::middleware::RequestContext;
 use crate::span::SpanContext;
 use crate::trace::{TraceContext, TraceContextBuilder, TraceRecord};
 
@@ -50,6 +52,14 @@ pub trait Subscriber: Sync + Send {
 
     /// Notifies this subscriber that a trace record has been verified and successfully recorded.
     fn on_record(&self, ctx: &mut TraceContext) {}
+
+    /// Notifies this subscriber that a service wrapped with
+    /// [`ReportLayer`](crate::middleware::ReportLayer) returned an error.
+    ///
+    /// The request is also inserted into the extensions of the trace context,
+    /// so it is still available in [`on_end`](Self::on_end).
+    #[cfg(feature = "tower")]
+    fn on_request_error(&self, ctx: &mut TraceContext, req: &RequestContext) {}
 }
 
 /// Default error subscriber.
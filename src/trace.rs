@@ -1,11 +1,13 @@
 extern crate alloc;
 
-use alloc::string::ToString;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
 use alloc::sync::{Arc, Weak};
 use alloc::vec::Vec;
+use core::any::Any;
 use core::error::Error;
 use core::hash::{Hash, Hasher};
-use core::marker::{Send, Sync};
+use core::marker::{PhantomData, Send, Sync};
 use core::ops::Index;
 use core::sync::atomic::{self, AtomicBool, Ordering};
 use core::{cmp, fmt};
@@ -18,11 +20,12 @@ type AtomicHash = portable_atomic::AtomicU64;
 #[cfg(not(feature = "std"))]
 type AtomicHash = portable_atomic::AtomicU32;
 
-use crate::data::{Id, Metadata};
+use crate::data::{Classification, Id, Metadata, Severity};
 use crate::dlog;
 use crate::downcast::Downcasted;
 use crate::extensions::{Extension, Extensions, ExtensionsInner, ExtensionsMut};
 use crate::extract::{Extract, Extractable};
+use crate::field::FieldVisitor;
 use crate::global::{for_each_subscriber, get_formatter};
 use crate::location::Location;
 
@@ -33,17 +36,31 @@ pub struct TraceRecord {
     /// The location of the error.
     pub location: Location,
     /// The name of the error derived from [`Metadata::name`].
-    pub name: &'static str,
+    pub name: Cow<'static, str>,
     /// The target of the error derived from [`Metadata::target`].
-    pub target: &'static str,
+    pub target: Cow<'static, str>,
     /// The target id of the error derived from [`Metadata::target_id`].
     pub target_id: Id,
     /// The id of the error derived from [`Metadata::id`].
     pub id: Id,
     /// Indicates whether the inherited inner error forwards its [`Display`](core::fmt::Display) implementation.
     pub is_transparent: bool,
+    /// The stable error code derived from [`Metadata::code`].
+    pub code: Option<Cow<'static, str>>,
+    /// The severity of the error derived from [`Metadata::severity`].
+    pub(crate) severity: Severity,
+    /// The classification of the error derived from [`Metadata::classification`].
+    pub(crate) classification: Classification,
+    /// The HTTP status code of the error derived from [`Metadata::status`].
+    pub(crate) status: Option<u16>,
+    /// The gRPC status code of the error derived from [`Metadata::grpc_code`].
+    pub(crate) grpc_code: Option<Cow<'static, str>>,
+    /// The message attached with [`Result::context()`](crate::result::Result::context).
+    pub(crate) context: Option<Cow<'static, str>>,
+    /// The values attached with [`Result::attach()`](crate::result::Result::attach).
+    pub(crate) attachments: Vec<Arc<dyn Any + Send + Sync>>,
     /// The inherited error.
-    pub(crate) inner: Option<Weak<dyn Error + Send + Sync>>,
+    pub(crate) inner: Option<Weak<dyn ErrorMetadata>>,
     /// Flag to switch between formatting methods.
     pub(crate) format_span: Arc<AtomicBool>,
 }
@@ -58,16 +75,22 @@ impl fmt::Debug for TraceRecord {
             .field("target_id", &self.target_id)
             .field("id", &self.id)
             .field("is_transparent", &self.is_transparent)
+            .field("code", &self.code)
+            .field("severity", &self.severity)
+            .field("classification", &self.classification)
+            .field("status", &self.status)
+            .field("grpc_code", &self.grpc_code)
+            .field("context", &self.context)
+            .field("attachments", &self.attachments.len())
             .finish()
     }
 }
 
 impl fmt::Display for TraceRecord {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.format_span.store(false, atomic::Ordering::Relaxed);
-        let r = get_formatter().format_record(self, f);
-        self.format_span.store(true, atomic::Ordering::Relaxed);
-        r
+        TraceRecord::without_span_format(&self.format_span, || {
+            get_formatter().format_record(self, f)
+        })
     }
 }
 
@@ -79,22 +102,121 @@ impl TraceRecord {
     {
         Self {
             location: core::panic::Location::caller().into(),
-            name: error.name(),
-            target: error.target(),
+            name: error.name().into(),
+            target: error.target().into(),
             target_id: *error.target_id(),
             id: *error.id(),
             is_transparent: error.is_transparent(),
+            code: error.code().map(Cow::Borrowed),
+            severity: error.severity(),
+            classification: error.classification(),
+            status: error.status(),
+            grpc_code: error.grpc_code().map(Cow::Borrowed),
+            context: None,
+            attachments: Vec::new(),
             inner: Some(Arc::downgrade(&error.inner())),
             format_span: ctx.format_span.clone(),
         }
     }
 
+    /// Calls `f` while nested spans are rendered with their message only.
+    ///
+    /// The previous value of the flag is restored afterwards,
+    /// so nested calls don't switch back to the span format too early.
+    pub(crate) fn without_span_format<R>(format_span: &AtomicBool, f: impl FnOnce() -> R) -> R {
+        let previous = format_span.swap(false, atomic::Ordering::Relaxed);
+        let r = f();
+        format_span.store(previous, atomic::Ordering::Relaxed);
+        r
+    }
+
+    /// Returns the severity of the error derived from [`Metadata::severity`].
+    #[inline]
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the classification of the error derived from [`Metadata::classification`].
+    #[inline]
+    pub fn classification(&self) -> Classification {
+        self.classification
+    }
+
+    /// Returns the HTTP status code of the error derived from [`Metadata::status`].
+    #[inline]
+    pub fn status(&self) -> Option<u16> {
+        self.status
+    }
+
+    /// Returns the gRPC status code of the error derived from [`Metadata::grpc_code`].
+    #[inline]
+    pub fn grpc_code(&self) -> Option<&str> {
+        self.grpc_code.as_deref()
+    }
+
+    /// Returns the message attached with [`Result::context()`](crate::result::Result::context)
+    /// or [`Result::with_context()`](crate::result::Result::with_context).
+    #[inline]
+    pub fn context(&self) -> Option<&str> {
+        self.context.as_deref()
+    }
+
+    /// Returns the values of type `T` attached to this record
+    /// with [`Result::attach()`](crate::result::Result::attach).
+    #[inline]
+    pub fn attachments<T>(&self) -> impl Iterator<Item = &T>
+    where
+        T: Any,
+    {
+        self.attachments
+            .iter()
+            .filter_map(|v| v.downcast_ref::<T>())
+    }
+
+    /// Returns the help message of the inherited error derived from [`Metadata::help`].
+    #[inline]
+    pub fn help(&self) -> Option<String> {
+        self.metadata_ref().and_then(|error| error.help())
+    }
+
+    /// Returns the note of the inherited error derived from [`Metadata::note`].
+    #[inline]
+    pub fn note(&self) -> Option<String> {
+        self.metadata_ref().and_then(|error| error.note())
+    }
+
     /// Returns the inherited error.
     ///
     /// The error has the same lifetime as [`TraceContext`],
     /// so the function will return a valid value if the context still exists.
     #[inline]
     pub fn error_ref(&self) -> Option<Arc<dyn Error + Send + Sync>> {
+        self.metadata_ref().map(ErrorMetadata::into_error)
+    }
+
+    /// Visits the fields of the inherited error, see [`Metadata::visit_fields()`].
+    ///
+    /// Nothing is visited if the error was already dropped.
+    #[inline]
+    pub fn visit_fields(&self, visitor: &mut dyn FieldVisitor) {
+        if let Some(error) = self.metadata_ref() {
+            error.visit_fields(visitor);
+        }
+    }
+
+    /// Visits the trace contexts of errors that are nested in fields of the inherited error,
+    /// for e.g. `Box<Ec>` or `Vec<Ec>`.
+    pub fn visit_nested<F>(&self, mut visitor: F)
+    where
+        F: FnMut(&TraceContext),
+    {
+        if let Some(error) = self.metadata_ref() {
+            error.visit_nested(&mut visitor);
+        }
+    }
+
+    #[inline]
+    fn metadata_ref(&self) -> Option<Arc<dyn ErrorMetadata>> {
         self.inner
             .as_ref()
             .expect("Weak reference upgrade should never fail. Please open an issue.")
@@ -107,6 +229,7 @@ impl Hash for TraceRecord {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.location.hash(state);
         self.name.hash(state);
+        self.context.hash(state);
     }
 }
 
@@ -115,7 +238,7 @@ impl Eq for TraceRecord {}
 impl PartialEq for TraceRecord {
     #[inline]
     fn eq(&self, other: &TraceRecord) -> bool {
-        self.location == other.location && self.name == other.name
+        self.location == other.location && self.name == other.name && self.context == other.context
     }
 }
 
@@ -123,7 +246,10 @@ impl Ord for TraceRecord {
     #[inline]
     fn cmp(&self, other: &TraceRecord) -> cmp::Ordering {
         match Ord::cmp(&self.location, &other.location) {
-            cmp::Ordering::Equal => Ord::cmp(&self.name, &other.name),
+            cmp::Ordering::Equal => match Ord::cmp(&self.name, &other.name) {
+                cmp::Ordering::Equal => Ord::cmp(&self.context, &other.context),
+                cmp => cmp,
+            },
             cmp => cmp,
         }
     }
@@ -132,10 +258,7 @@ impl Ord for TraceRecord {
 impl PartialOrd for TraceRecord {
     #[inline]
     fn partial_cmp(&self, other: &TraceRecord) -> Option<cmp::Ordering> {
-        match PartialOrd::partial_cmp(&self.location, &other.location) {
-            Option::Some(cmp::Ordering::Equal) => PartialOrd::partial_cmp(&self.name, &other.name),
-            cmp => cmp,
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -153,6 +276,9 @@ pub struct TraceContext {
     pub(crate) dropped: Arc<AtomicBool>,
     /// User attached data container.
     pub(crate) extensions: ExtensionsInner,
+    /// The backtrace captured at the creation of the first `Span`.
+    #[cfg(feature = "backtrace")]
+    pub(crate) backtrace: Option<std::backtrace::Backtrace>,
 }
 
 impl fmt::Debug for TraceContext {
@@ -169,10 +295,9 @@ impl fmt::Display for TraceContext {
         if self.trace.is_empty() {
             return write!(f, "TraceContext empty");
         }
-        self.format_span.store(false, atomic::Ordering::Relaxed);
-        let r = get_formatter().format_trace(self, f);
-        self.format_span.store(true, atomic::Ordering::Relaxed);
-        r
+        TraceRecord::without_span_format(&self.format_span, || {
+            get_formatter().format_trace(self, f)
+        })
     }
 }
 
@@ -205,6 +330,12 @@ impl<'a> TraceRecordIterator<'a> {
             None => 0,
         }
     }
+
+    /// Returns `true` if the trace context contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 impl<'a> Iterator for TraceRecordIterator<'a> {
@@ -270,6 +401,76 @@ impl TraceContext {
             .expect("Context must have at least one or more records. Please open an issue.")
     }
 
+    /// Gets the highest severity of all records.
+    #[inline]
+    pub fn severity(&self) -> Severity {
+        self.trace
+            .iter()
+            .map(|r| r.severity)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Gets the HTTP status code of the outermost non-transparent record that declares one.
+    ///
+    /// Transparent records are skipped, so a status of a wrapping enum doesn't shadow
+    /// the status of the error that it forwards.
+    #[inline]
+    pub fn status(&self) -> Option<u16> {
+        self.trace
+            .iter()
+            .rev()
+            .filter(|r| !r.is_transparent)
+            .find_map(|r| r.status)
+    }
+
+    /// Gets the gRPC status code of the outermost non-transparent record that declares one.
+    ///
+    /// The code is resolved like [`TraceContext::status()`].
+    #[inline]
+    pub fn grpc_code(&self) -> Option<&str> {
+        self.trace
+            .iter()
+            .rev()
+            .filter(|r| !r.is_transparent)
+            .find_map(|r| r.grpc_code())
+    }
+
+    /// Gets the values of type `T` attached to the records of the trace, in propagation order.
+    ///
+    /// Unlike [`Extensions`], multiple values of the same type can be attached,
+    /// see [`Result::attach()`](crate::result::Result::attach).
+    #[inline]
+    pub fn attachments<T>(&self) -> impl Iterator<Item = &T>
+    where
+        T: Any,
+    {
+        self.trace.iter().flat_map(TraceRecord::attachments::<T>)
+    }
+
+    /// Attaches a value to the last record of the trace.
+    #[inline]
+    pub(crate) fn attach<T>(&mut self, value: T)
+    where
+        T: Any + Send + Sync,
+    {
+        if let Some(rec) = self.trace.last_mut() {
+            rec.attachments.push(Arc::new(value));
+        }
+    }
+
+    /// Gets the backtrace captured at the creation of the origin error.
+    ///
+    /// A backtrace is only captured if the origin error is declared with the `#[backtrace]` attribute.
+    /// Whether frames are actually resolved depends on the `RUST_BACKTRACE`
+    /// and `RUST_LIB_BACKTRACE` environment variables,
+    /// see [`Backtrace::capture`](std::backtrace::Backtrace::capture).
+    #[cfg(feature = "backtrace")]
+    #[inline]
+    pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        self.backtrace.as_ref()
+    }
+
     #[doc(hidden)]
     #[inline]
     pub fn insert(&mut self, record: TraceRecord) -> bool {
@@ -293,7 +494,7 @@ impl TraceContext {
             return true;
         }
 
-        return false;
+        false
     }
 }
 
@@ -302,6 +503,8 @@ pub struct TraceContextBuilder {
     trace: Vec<TraceRecord>,
     pub(crate) format_span: Arc<AtomicBool>,
     extensions: ExtensionsInner,
+    #[cfg(feature = "backtrace")]
+    pub(crate) backtrace: Option<std::backtrace::Backtrace>,
 }
 
 impl TraceContextBuilder {
@@ -311,6 +514,8 @@ impl TraceContextBuilder {
             trace: Vec::new(),
             format_span: Arc::new(AtomicBool::new(true)),
             extensions: ExtensionsInner::new(),
+            #[cfg(feature = "backtrace")]
+            backtrace: None,
         }
     }
 
@@ -347,6 +552,8 @@ impl TraceContextBuilder {
             format_span: self.format_span,
             dropped: Arc::new(AtomicBool::new(false)),
             extensions: self.extensions,
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace,
         }
     }
 }
@@ -372,16 +579,22 @@ pub trait TraceAccess {
     /// as its 'length'.
     fn len(&self) -> usize;
 
+    /// Returns `true` if the trace context contains no elements.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Returns an iterator over the slice of the trace records.
     ///
     /// The iterator yields all items from start to end.
-    fn iter(&self) -> TraceRecordIterator;
+    fn iter(&self) -> TraceRecordIterator<'_>;
 }
 
 impl TraceAccess for TraceContext {
     #[inline]
     fn id(&self) -> Id {
-        return (core::ptr::addr_of!(self) as u64).into();
+        (core::ptr::addr_of!(self) as u64).into()
     }
 
     #[inline]
@@ -390,7 +603,7 @@ impl TraceAccess for TraceContext {
     }
 
     #[inline]
-    fn iter(&self) -> TraceRecordIterator {
+    fn iter(&self) -> TraceRecordIterator<'_> {
         TraceRecordIterator {
             index: Some(&self.trace),
             pos: 0,
@@ -399,6 +612,52 @@ impl TraceAccess for TraceContext {
     }
 }
 
+/// Interface to decide whether a failed operation is worth to be retried.
+///
+/// The effective classification is the highest [`Classification`] of all errors in the chain,
+/// including errors that are nested in fields like `Vec<Ec>`:
+///
+/// - [`Permanent`](Classification::Permanent) if any error is permanent.
+/// - [`Transient`](Classification::Transient) if any error is transient and none is permanent.
+/// - [`Unclassified`](Classification::Unclassified) otherwise.
+///
+/// ```ignore
+/// loop {
+///     match fetch() {
+///         Err(ec) if ec.classify().is_transient() => continue,
+///         result => break result,
+///     }
+/// }
+/// ```
+pub trait Classify {
+    /// Returns the effective classification of the error chain.
+    fn classify(&self) -> Classification;
+}
+
+impl Classify for TraceContext {
+    fn classify(&self) -> Classification {
+        let mut classification = Classification::Unclassified;
+        for record in &self.trace {
+            classification = classification.max(record.classification);
+            record.visit_nested(|ctx| classification = classification.max(ctx.classify()));
+        }
+        classification
+    }
+}
+
+impl<T> Classify for T
+where
+    T: Traceable + ?Sized,
+{
+    #[inline]
+    fn classify(&self) -> Classification {
+        match self.trace_ref() {
+            Some(ctx) => ctx.classify(),
+            None => self.inner().classification(),
+        }
+    }
+}
+
 impl Extension for TraceContext {
     #[inline]
     fn extensions(&self) -> Extensions<'_> {
@@ -412,7 +671,6 @@ impl Extension for TraceContext {
 }
 
 impl Extract for TraceContext {
-    #[inline]
     fn get<'a, E>(&'a self) -> Option<Downcasted<'a, E>>
     where
         E: Error + Extractable + 'static,
@@ -426,10 +684,22 @@ impl Extract for TraceContext {
                 return Some(Downcasted::<E>::new(origin));
             }
         }
-        return None;
+
+        // Descend into nested error contexts.
+        let mut nested = None;
+        for e in self {
+            e.visit_nested(|ctx| {
+                if nested.is_none() {
+                    nested = ctx.get::<E>().map(Downcasted::rebind);
+                }
+            });
+            if nested.is_some() {
+                break;
+            }
+        }
+        nested
     }
 
-    #[inline]
     fn has<'a, E>(&'a self) -> bool
     where
         E: Error + Extractable + 'static,
@@ -443,7 +713,16 @@ impl Extract for TraceContext {
                 return true;
             }
         }
-        return false;
+
+        // Descend into nested error contexts.
+        let mut nested = false;
+        for e in self {
+            e.visit_nested(|ctx| nested = nested || ctx.has::<E>());
+            if nested {
+                return true;
+            }
+        }
+        false
     }
 }
 
@@ -458,15 +737,171 @@ pub trait Traceable {
     /// Returns a trace context reference.
     fn trace_ref(&self) -> Option<&TraceContext>;
 
+    #[doc(hidden)]
+    /// Returns a mutable trace context reference.
+    fn trace_mut(&mut self) -> Option<&mut TraceContext> {
+        None
+    }
+
     #[doc(hidden)]
     /// Takes the trace context.
     fn take_trace(&mut self) -> Option<TraceContext>;
 
     #[doc(hidden)]
     /// Returns the inherited error.
-    fn inner(&self) -> Arc<dyn Error + Send + Sync>;
+    fn inner(&self) -> Arc<dyn ErrorMetadata>;
 
     #[doc(hidden)]
     /// Inserts a new trace record to the error chain.
     fn insert(&mut self, record: TraceRecord) -> bool;
 }
+
+#[doc(hidden)]
+/// Combines [`Error`] and [`Metadata`] for the inherited error of a record.
+pub trait ErrorMetadata: Error + Metadata + Send + Sync {
+    /// Converts the error to a plain [`Error`] trait object.
+    fn into_error(self: Arc<Self>) -> Arc<dyn Error + Send + Sync>;
+}
+
+impl<T> ErrorMetadata for T
+where
+    T: Error + Metadata + Send + Sync + 'static,
+{
+    #[inline]
+    fn into_error(self: Arc<Self>) -> Arc<dyn Error + Send + Sync> {
+        self
+    }
+}
+
+#[doc(hidden)]
+/// Wrapper to visit the trace of a nested error without naming its type.
+///
+/// Uses autoref specialization with the following priority:
+/// 1. [`Traceable`] visits the trace of the error context.
+/// 2. [`Metadata`] descends into the fields of the error.
+/// 3. Any other type is skipped.
+///
+/// Call it with `(&&&NestedProbe(&value)).probe_nested(visitor)`.
+pub struct NestedProbe<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait TraceableNested {
+    fn probe_nested(&self, visitor: &mut dyn FnMut(&TraceContext));
+}
+
+impl<'a, 'b, T: Traceable + ?Sized> TraceableNested for &'b &'b NestedProbe<'a, T> {
+    #[inline]
+    fn probe_nested(&self, visitor: &mut dyn FnMut(&TraceContext)) {
+        if let Some(ctx) = self.0.trace_ref() {
+            visitor(ctx);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait MetadataNested {
+    fn probe_nested(&self, visitor: &mut dyn FnMut(&TraceContext));
+}
+
+impl<'a, 'b, T: Metadata + ?Sized> MetadataNested for &'b NestedProbe<'a, T> {
+    #[inline]
+    fn probe_nested(&self, visitor: &mut dyn FnMut(&TraceContext)) {
+        self.0.visit_nested(visitor);
+    }
+}
+
+#[doc(hidden)]
+pub trait UntracedNested {
+    #[inline]
+    fn probe_nested(&self, _visitor: &mut dyn FnMut(&TraceContext)) {}
+}
+
+impl<'a, T: ?Sized> UntracedNested for NestedProbe<'a, T> {}
+
+#[doc(hidden)]
+/// Wrapper to forward the severity of a transparent field without naming its type.
+///
+/// Uses autoref specialization with the following priority:
+/// 1. [`Metadata`] returns the severity of the field.
+/// 2. Any other type returns the given default.
+///
+/// Call it with `(&&SeverityProbe(&value)).probe_severity(default)`.
+pub struct SeverityProbe<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait MetadataSeverity {
+    fn probe_severity(&self, default: Severity) -> Severity;
+}
+
+impl<'a, 'b, T: Metadata + ?Sized> MetadataSeverity for &'b SeverityProbe<'a, T> {
+    #[inline]
+    fn probe_severity(&self, _default: Severity) -> Severity {
+        self.0.severity()
+    }
+}
+
+#[doc(hidden)]
+pub trait UntracedSeverity {
+    #[inline]
+    fn probe_severity(&self, default: Severity) -> Severity {
+        default
+    }
+}
+
+impl<'a, T: ?Sized> UntracedSeverity for SeverityProbe<'a, T> {}
+
+#[doc(hidden)]
+/// Wrapper to detect whether a type implements [`Traceable`] without naming the type.
+///
+/// Uses autoref specialization, therefore both [`TraceableProbe`] and [`UntraceableProbe`]
+/// need to be in scope when calling `(&mut TraceProbe(&mut value)).probe_trace()`.
+pub struct TraceProbe<'a, T>(pub &'a mut T);
+
+#[doc(hidden)]
+pub trait TraceableProbe {
+    fn probe_trace(&mut self) -> Option<TraceContext>;
+}
+
+impl<'a, T: Traceable> TraceableProbe for TraceProbe<'a, T> {
+    #[inline]
+    fn probe_trace(&mut self) -> Option<TraceContext> {
+        self.0.take_trace()
+    }
+}
+
+#[doc(hidden)]
+pub trait UntraceableProbe {
+    #[inline]
+    fn probe_trace(&mut self) -> Option<TraceContext> {
+        None
+    }
+}
+
+impl<'a, 'b, T> UntraceableProbe for &'b mut TraceProbe<'a, T> {}
+
+#[doc(hidden)]
+/// Wrapper to detect whether a type implements [`Traceable`] without a value of the type.
+///
+/// Uses autoref specialization, therefore both [`TraceableType`] and [`UntraceableType`]
+/// need to be in scope when calling `(&&TypeProbe::<T>(PhantomData)).is_traceable()`.
+pub struct TypeProbe<T: ?Sized>(pub PhantomData<T>);
+
+#[doc(hidden)]
+pub trait TraceableType {
+    #[inline]
+    fn is_traceable(&self) -> bool {
+        true
+    }
+}
+
+impl<'a, T: Traceable + ?Sized> TraceableType for &'a TypeProbe<T> {}
+
+#[doc(hidden)]
+pub trait UntraceableType {
+    #[inline]
+    fn is_traceable(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> UntraceableType for TypeProbe<T> {}
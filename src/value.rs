@@ -0,0 +1,80 @@
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+
+use crate::field::FieldVisitor;
+
+/// A value of a field, see [`Fields`].
+pub(crate) enum Value {
+    Str(String),
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+}
+
+/// Collects the fields of an error, values only known by [`Debug`] are rendered as string.
+#[derive(Default)]
+pub(crate) struct Fields(pub(crate) Vec<(String, Value)>);
+
+impl Fields {
+    /// Returns the collected fields in the order they were visited.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.0.iter().map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Passes the collected fields to another visitor.
+    pub(crate) fn visit(&self, visitor: &mut dyn FieldVisitor) {
+        for (name, value) in &self.0 {
+            match value {
+                Value::Str(v) => visitor.record_str(name, v),
+                Value::Bool(v) => visitor.record_bool(name, *v),
+                Value::I64(v) => visitor.record_i64(name, *v),
+                Value::U64(v) => visitor.record_u64(name, *v),
+                Value::F64(v) => visitor.record_f64(name, *v),
+            }
+        }
+    }
+}
+
+impl FieldVisitor for Fields {
+    fn record_debug(&mut self, name: &str, value: &dyn Debug) {
+        self.0
+            .push((name.to_string(), Value::Str(format!("{:?}", value))));
+    }
+
+    fn record_str(&mut self, name: &str, value: &str) {
+        self.0
+            .push((name.to_string(), Value::Str(value.to_string())));
+    }
+
+    fn record_bool(&mut self, name: &str, value: bool) {
+        self.0.push((name.to_string(), Value::Bool(value)));
+    }
+
+    fn record_i64(&mut self, name: &str, value: i64) {
+        self.0.push((name.to_string(), Value::I64(value)));
+    }
+
+    fn record_u64(&mut self, name: &str, value: u64) {
+        self.0.push((name.to_string(), Value::U64(value)));
+    }
+
+    fn record_f64(&mut self, name: &str, value: f64) {
+        self.0.push((name.to_string(), Value::F64(value)));
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Str(v) => f.write_str(v),
+            Value::Bool(v) => fmt::Display::fmt(v, f),
+            Value::I64(v) => fmt::Display::fmt(v, f),
+            Value::U64(v) => fmt::Display::fmt(v, f),
+            Value::F64(v) => fmt::Display::fmt(v, f),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "anyhow")]
+
+use errore::interop::anyhow::IntoAnyhow;
+use errore::prelude::*;
+use test_utils::*;
+
+#[test]
+fn test_anyhow_from() {
+    pub mod x {
+        use super::*;
+
+        #[derive(Error, Debug)]
+        pub enum Error {
+            #[error("Failed to fetch")]
+            Fetch(#[from] anyhow::Error),
+        }
+
+        pub fn fetch() -> std::result::Result<(), anyhow::Error> {
+            std::result::Result::Err(
+                anyhow::anyhow!("Connection refused").context("Request failed"),
+            )
+        }
+
+        pub fn load() -> Result<(), Ec> {
+            fetch()?;
+            Ok(())
+        }
+    }
+
+    let ec = x::load().unwrap_err();
+    assert!(matches!(ec.error(), x::Error::Fetch(_)));
+    let source = std::error::Error::source(ec.error()).unwrap();
+    assert_eq_text!(source.to_string(), "Request failed");
+    assert_eq_text!(source.source().unwrap().to_string(), "Connection refused");
+}
+
+#[test]
+fn test_anyhow_into() {
+    pub mod x {
+        use super::*;
+
+        pub mod a {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            pub enum Error {
+                #[error("Unexpected end of file")]
+                Eof,
+            }
+
+            pub fn parse() -> Result<(), Ec> {
+                err!(Error::Eof)
+            }
+        }
+
+        pub mod b {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            pub enum Error {
+                #[error("Failed to load config")]
+                Load(#[from] a::Ec),
+            }
+
+            pub fn load() -> Result<(), Ec> {
+                a::parse()?;
+                Ok(())
+            }
+        }
+
+        pub fn run() -> anyhow::Result<()> {
+            #[cfg(feature = "nightly")]
+            b::load().into_anyhow()?;
+            #[cfg(not(feature = "nightly"))]
+            b::load().map_err(IntoAnyhow::into_anyhow)?;
+            anyhow::Ok(())
+        }
+    }
+
+    let error = x::run().unwrap_err();
+    assert_eq_text!(
+        error.to_string(),
+        "<errore::b::Load> Failed to load config at tests/test_anyhow.rs:66:17"
+    );
+    let chain = error.chain().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        chain,
+        [
+            "<errore::b::Load> Failed to load config at tests/test_anyhow.rs:66:17",
+            "<errore::a::Eof> Unexpected end of file at tests/test_anyhow.rs:52:17",
+            "Failed to load config",
+            "Unexpected end of file",
+            "Unexpected end of file",
+        ]
+    );
+
+    // The trace is carried by the converted error.
+    let traced = error
+        .downcast_ref::<errore::interop::TracedError>()
+        .unwrap();
+    assert_eq!(traced.trace().len(), 2);
+    assert!(traced.trace().has::<x::a::Error>());
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "nightly")]
+
+use std::path::PathBuf;
+
+use errore::prelude::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Attempt(u32);
+
+pub mod db {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("Connection lost")]
+        ConnectionLost,
+    }
+
+    pub fn query(sql: &str) -> Result<(), Ec> {
+        err!(Error::ConnectionLost).attach(sql.to_string())
+    }
+
+    pub fn query_retry(sql: &str, attempts: u32) -> Result<(), Ec> {
+        let mut attempt = 1;
+        loop {
+            match query(sql).attach(Attempt(attempt)) {
+                Err(ec) if attempt == attempts => return Err(ec),
+                _ => attempt += 1,
+            }
+        }
+    }
+}
+
+pub mod app {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("Failed to load users")]
+        Load(#[from] db::Ec),
+    }
+
+    pub fn load(path: &str) -> Result<(), Ec> {
+        db::query_retry("SELECT * FROM users", 3).attach_with(|| PathBuf::from(path))?;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_attach() {
+    let ec = db::query("SELECT 1").unwrap_err();
+    assert_eq!(ec.attachments::<String>().collect::<Vec<_>>(), ["SELECT 1"]);
+    assert_eq!(ec.attachments::<Attempt>().count(), 0);
+    assert_eq!(ec.trace().last().attachments::<String>().count(), 1);
+}
+
+#[test]
+fn test_attach_propagation() {
+    let ec = app::load("users.toml").unwrap_err();
+    assert_eq!(
+        ec.attachments::<String>().collect::<Vec<_>>(),
+        ["SELECT * FROM users"]
+    );
+    assert_eq!(
+        ec.attachments::<Attempt>().collect::<Vec<_>>(),
+        [&Attempt(3)]
+    );
+    assert_eq!(
+        ec.attachments::<PathBuf>().collect::<Vec<_>>(),
+        [&PathBuf::from("users.toml")]
+    );
+
+    // all values are stored on the records where they were attached
+    let trace = ec.trace();
+    assert_eq!(trace.first().attachments::<String>().count(), 1);
+    assert_eq!(trace.first().attachments::<Attempt>().count(), 1);
+    assert_eq!(trace.first().attachments::<PathBuf>().count(), 1);
+    assert_eq!(trace.last().attachments::<PathBuf>().count(), 0);
+}
+
+#[test]
+fn test_attach_multiple() {
+    fn step(attempt: u32) -> Result<(), db::Ec> {
+        db::query("SELECT 1").attach(Attempt(attempt))?;
+        Ok(())
+    }
+
+    fn run() -> Result<(), db::Ec> {
+        step(1).attach(Attempt(2))?;
+        Ok(())
+    }
+
+    let ec = run().unwrap_err();
+    assert_eq!(
+        ec.attachments::<Attempt>().collect::<Vec<_>>(),
+        [&Attempt(1), &Attempt(2)]
+    );
+    assert_eq!(ec.trace().attachments::<Attempt>().count(), 2);
+}
+
+#[test]
+fn test_attach_ok() {
+    let result: Result<u8, db::Ec> = Ok(1);
+    let result = result
+        .attach(Attempt(1))
+        .attach_with(|| -> Attempt { unreachable!() });
+    assert_eq!(result.unwrap(), 1);
+}
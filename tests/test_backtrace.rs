@@ -0,0 +1,64 @@
+#![cfg(feature = "backtrace")]
+#![feature(error_generic_member_access)]
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error as _;
+
+use errore::prelude::*;
+
+#[test]
+fn test_backtrace() {
+    pub mod x {
+        use super::*;
+
+        pub mod a {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[backtrace]
+            #[error("Disk is full")]
+            pub struct ErrorStruct;
+
+            pub fn write() -> Result<(), Ec> {
+                err!(ErrorStruct)
+            }
+        }
+
+        pub mod b {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[allow(clippy::large_enum_variant)]
+            pub enum Error {
+                #[error("Invalid path")]
+                InvalidPath,
+                #[error(transparent)]
+                Write(#[from] a::Ec),
+            }
+
+            pub fn save() -> Result<(), Ec> {
+                a::write()?;
+                Ok(())
+            }
+        }
+    }
+
+    // The environment is read once at the first capture.
+    std::env::set_var("RUST_LIB_BACKTRACE", "1");
+
+    // Errors without the attribute don't capture a backtrace.
+    let ec = x::b::Ec::new(x::b::Error::InvalidPath);
+    assert!(ec.trace().backtrace().is_none());
+    assert!(!ec.trace().to_string().contains("Backtrace:"));
+
+    // The backtrace of the origin error is moved with the trace context.
+    let ec = x::b::save().unwrap_err();
+    let backtrace = ec.trace().backtrace().unwrap();
+    assert_eq!(backtrace.status(), BacktraceStatus::Captured);
+    assert!(ec.trace().to_string().contains("\n\nBacktrace:\n"));
+
+    // The span provides the backtrace as source of the context.
+    let span = ec.source().unwrap();
+    let provided = std::error::request_ref::<Backtrace>(span).unwrap();
+    assert!(std::ptr::eq(provided, backtrace));
+}
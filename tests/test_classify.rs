@@ -0,0 +1,141 @@
+#![allow(clippy::large_enum_variant)]
+
+use errore::prelude::*;
+
+pub mod net {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    #[errore(transient)]
+    pub enum Error {
+        #[error("Connection timed out")]
+        Timeout,
+        #[error("Connection refused")]
+        Refused,
+        #[error("Host not found")]
+        #[errore(permanent)]
+        NotFound,
+    }
+}
+
+pub mod db {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error(transparent)]
+        Net(#[from] net::Ec),
+        #[error("Invalid query")]
+        #[errore(permanent)]
+        InvalidQuery,
+        #[error("Batch failed")]
+        Batch(Vec<net::Ec>),
+        #[error("Busy")]
+        Busy,
+    }
+
+    pub fn query(error: net::Error) -> Result<(), Ec> {
+        Err(net::Ec::new(error))?;
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug)]
+#[errore(context = RateLimitEc)]
+#[error("Rate limit exceeded")]
+#[errore(transient, code = "E-RATE")]
+pub struct RateLimit;
+
+#[test]
+fn test_classification() {
+    assert_eq!(
+        net::Error::Timeout.classification(),
+        Classification::Transient
+    );
+    assert_eq!(
+        net::Error::NotFound.classification(),
+        Classification::Permanent
+    );
+    assert_eq!(
+        db::Error::Busy.classification(),
+        Classification::Unclassified
+    );
+    assert_eq!(
+        db::Error::InvalidQuery.classification(),
+        Classification::Permanent
+    );
+
+    let ec = RateLimitEc::new(RateLimit);
+    assert_eq!(ec.classification(), Classification::Transient);
+    assert_eq!(ec.code(), Some("E-RATE"));
+    assert_eq!(
+        ec.trace().last().classification(),
+        Classification::Transient
+    );
+    assert_eq!(Classification::Permanent.to_string(), "permanent");
+}
+
+#[test]
+fn test_classify() {
+    let ec = db::query(net::Error::Timeout).unwrap_err();
+    assert_eq!(ec.classification(), Classification::Unclassified);
+    assert_eq!(ec.trace().classify(), Classification::Transient);
+    assert!(ec.classify().is_transient());
+
+    let ec = db::query(net::Error::NotFound).unwrap_err();
+    assert!(ec.classify().is_permanent());
+
+    let ec = db::Ec::invalid_query();
+    assert!(ec.classify().is_permanent());
+
+    let ec = db::Ec::busy();
+    assert_eq!(ec.classify(), Classification::Unclassified);
+
+    let ec = db::Ec::batch(vec![
+        net::Ec::new(net::Error::Timeout),
+        net::Ec::new(net::Error::Refused),
+    ]);
+    assert!(ec.classify().is_transient());
+
+    let ec = db::Ec::batch(vec![
+        net::Ec::new(net::Error::Timeout),
+        net::Ec::new(net::Error::NotFound),
+    ]);
+    assert!(ec.classify().is_permanent());
+}
+
+#[test]
+fn test_classify_retry() {
+    let mut attempts = 0;
+    let result = loop {
+        attempts += 1;
+        let error = if attempts < 3 {
+            net::Error::Timeout
+        } else {
+            net::Error::NotFound
+        };
+        match db::query(error) {
+            Err(ec) if ec.classify().is_transient() => continue,
+            result => break result,
+        }
+    };
+    assert_eq!(attempts, 3);
+    assert!(result.unwrap_err().classify().is_permanent());
+}
+
+#[test]
+fn test_classification_format_args() {
+    #[derive(Error, Debug)]
+    #[error("{} of {}", transient, permanent)]
+    pub struct Attempts {
+        transient: u32,
+        permanent: u32,
+    }
+
+    let error = Attempts {
+        transient: 1,
+        permanent: 3,
+    };
+    assert_eq!(error.to_string(), "1 of 3");
+    assert_eq!(error.classification(), Classification::Unclassified);
+}
@@ -0,0 +1,83 @@
+use errore::prelude::*;
+use test_utils::*;
+
+pub mod x {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("Invalid captcha '{hash}'")]
+        WrongCaptcha { hash: String },
+        #[error("Too many attempts ({0}/{1})")]
+        TooManyAttempts(u32, u32),
+        #[error("Request timed out")]
+        HTTPTimeout,
+        #[error("Invalid type")]
+        Type,
+        #[error("Created")]
+        New,
+        #[error(transparent)]
+        Transparent(#[from] std::io::Error),
+        #[error("Attachments are missing")]
+        Attachments,
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum Error<T>
+where
+    T: std::fmt::Debug + std::fmt::Display + Send + Sync + 'static,
+{
+    #[error("Invalid value '{0}'")]
+    Invalid(T),
+}
+
+#[test]
+fn test_constructor() {
+    let ec = x::Ec::wrong_captcha("1a2b".into());
+    assert!(ec.is_wrong_captcha());
+    assert!(!ec.is_too_many_attempts());
+    assert_eq_text!(ec.error().to_string(), "Invalid captcha '1a2b'");
+    assert!(matches!(
+        ec.error(),
+        x::Error::WrongCaptcha { hash } if hash == "1a2b"
+    ));
+
+    let ec = x::Ec::too_many_attempts(3, 5);
+    assert!(ec.is_too_many_attempts());
+    assert_eq_text!(ec.error().to_string(), "Too many attempts (3/5)");
+
+    let ec = x::Ec::http_timeout();
+    assert!(ec.is_http_timeout());
+    assert!(!ec.is_wrong_captcha());
+
+    let ec = x::Ec::r#type();
+    assert!(ec.is_type());
+
+    // Reserved names don't generate methods that collide with the context.
+    let ec = x::Ec::new(x::Error::New);
+    assert!(ec.is_new());
+    let ec = x::Ec::transparent(std::io::Error::other("disk full"));
+    assert!(ec.is_transparent());
+    assert!(!x::Ec::r#type().is_transparent());
+    let ec = x::Ec::new(x::Error::Attachments);
+    assert!(ec.is_attachments());
+    assert_eq!(ec.attachments::<String>().count(), 0);
+
+    let ec = Ec::invalid(1);
+    assert!(ec.is_invalid());
+    assert_eq_text!(ec.error().to_string(), "Invalid value '1'");
+}
+
+#[test]
+fn test_constructor_location() {
+    fn verify() -> Result<(), x::Ec> {
+        Err(x::Ec::wrong_captcha("1a2b".into()))
+    }
+
+    let ec = verify().unwrap_err();
+    assert_eq_text!(
+        ec.trace().first().location.to_string(),
+        "tests/test_constructor.rs:75:13"
+    );
+}
@@ -0,0 +1,105 @@
+use errore::prelude::*;
+use test_utils::*;
+
+#[test]
+fn test_context_name() {
+    pub mod x {
+        use super::*;
+
+        #[derive(Error, Debug)]
+        #[errore(context = ParseEc)]
+        pub enum ParseError {
+            #[error("Unexpected token '{0}'")]
+            Token(char),
+        }
+
+        #[derive(Error, Debug)]
+        #[errore(context = IoEc)]
+        #[error("Failed to read '{path}'")]
+        pub struct IoError {
+            pub path: String,
+        }
+
+        pub fn parse() -> Result<(), ParseEc> {
+            err!(ParseEc, ParseError::Token('%'))
+        }
+
+        pub fn read() -> Result<(), IoEc> {
+            err!(
+                IoEc,
+                IoError {
+                    path: "config.toml".into()
+                }
+            )
+        }
+    }
+
+    let ec = x::parse().unwrap_err();
+    assert_eq!(ec.trace().len(), 1);
+    assert_eq_text!(ec.error().to_string(), "Unexpected token '%'");
+    assert_eq_text!(ec.name(), "errore::x::Token");
+
+    let ec = x::read().unwrap_err();
+    assert_eq!(ec.trace().len(), 1);
+    assert_eq_text!(ec.error().to_string(), "Failed to read 'config.toml'");
+    assert_eq_text!(ec.name(), "errore::x::IoError");
+
+    let ec = x::ParseEc::new(x::ParseError::Token('$'));
+    assert_eq!(ec.trace().last().location.line(), 47);
+}
+
+#[test]
+fn test_context_from() {
+    pub mod x {
+        use super::*;
+
+        pub mod a {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[errore(context = ParseEc)]
+            pub enum ParseError {
+                #[error("Unexpected end of file")]
+                Eof,
+            }
+
+            pub fn parse() -> Result<(), ParseEc> {
+                err!(ParseEc, ParseError::Eof)
+            }
+        }
+
+        pub mod b {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[errore(context = LoadEc)]
+            pub enum LoadError {
+                #[error(transparent)]
+                Parse(#[from] a::ParseEc),
+            }
+
+            pub fn load() -> Result<(), LoadEc> {
+                a::parse()?;
+                Ok(())
+            }
+        }
+    }
+
+    // The trace is moved to the outer context, even if it isn't named 'Ec'.
+    let ec = x::b::load().unwrap_err();
+    let trace = ec.trace();
+    assert_eq!(trace.len(), 2);
+    assert_eq_text!(&trace.first().name, "errore::a::Eof");
+    assert_eq!(trace.first().location.line(), 67);
+    assert_eq_text!(&trace.last().name, "errore::b::Parse");
+    assert_eq!(trace.last().location.line(), 82);
+    assert!(ec.has::<x::a::ParseError>());
+
+    // Without the nightly `Result`, conversions are recorded by `From` instead of `?`.
+    let ec = x::a::ParseEc::new(x::a::ParseError::Eof);
+    let ec = x::b::LoadEc::from(ec);
+    assert_eq!(
+        ec.trace().len(),
+        if cfg!(feature = "nightly") { 1 } else { 2 }
+    );
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "nightly")]
+
+use errore::prelude::*;
+use test_utils::*;
+
+pub mod config {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("File '{0}' not found")]
+        NotFound(String),
+    }
+
+    pub fn read(path: &str) -> Result<String, Ec> {
+        err!(Error::NotFound(path.into()))
+    }
+
+    pub fn load(path: &str) -> Result<String, Ec> {
+        let config = read(path).context("loading config")?;
+        Ok(config)
+    }
+
+    pub fn init(path: &str) -> Result<String, Ec> {
+        load(path).with_context(|| format!("initializing from '{}'", path))
+    }
+}
+
+pub mod app {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("Failed to start")]
+        Start(#[from] config::Ec),
+    }
+
+    pub fn start() -> Result<(), Ec> {
+        config::init("app.toml")?;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_context_message() {
+    let ec = config::init("app.toml").unwrap_err();
+    assert_eq!(ec.trace().len(), 4);
+    assert_eq!(ec.trace().first().context(), None);
+    assert_eq!(ec.trace().iter()[1].context(), Some("loading config"));
+    assert_eq!(
+        ec.trace().last().context(),
+        Some("initializing from 'app.toml'")
+    );
+    // the error itself is unchanged
+    assert_eq_text!(ec.error().to_string(), "File 'app.toml' not found");
+    assert_eq_text!(
+        ec.trace().to_string(),
+        "
+Error: errore::config::NotFound
+╰─▶ <errore::config::NotFound> File 'app.toml' not found
+    ├╴ tests/test_context_message.rs:16:9
+    ├╴ loading config at tests/test_context_message.rs:20:33
+    ├╴ tests/test_context_message.rs:20:22
+    ╰╴ initializing from 'app.toml' at tests/test_context_message.rs:25:20"
+    );
+}
+
+#[test]
+fn test_context_message_nested() {
+    let ec = app::start().unwrap_err();
+    assert_eq_text!(
+        ec.trace().to_string(),
+        "
+Error: errore::app::Start
+├─▶ <errore::config::NotFound> File 'app.toml' not found
+│   ├╴ tests/test_context_message.rs:16:9
+│   ├╴ loading config at tests/test_context_message.rs:20:33
+│   ├╴ tests/test_context_message.rs:20:22
+│   ╰╴ initializing from 'app.toml' at tests/test_context_message.rs:25:20
+│
+╰─▶ <errore::app::Start> Failed to start
+    ╰╴ tests/test_context_message.rs:39:9"
+    );
+}
+
+#[test]
+fn test_context_message_ok() {
+    let mut called = false;
+    let result: Result<u8, config::Ec> = Ok(1);
+    let result = result.context("unused").with_context(|| {
+        called = true;
+        "unused"
+    });
+    assert_eq!(result.unwrap(), 1);
+    assert!(!called);
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "eyre")]
+
+use errore::interop::eyre::IntoReport;
+use errore::prelude::*;
+use test_utils::*;
+
+#[test]
+fn test_eyre() {
+    pub mod x {
+        use super::*;
+
+        pub mod a {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            pub enum Error {
+                #[error("Unexpected end of file")]
+                Eof,
+            }
+
+            pub fn parse() -> Result<(), Ec> {
+                err!(Error::Eof)
+            }
+        }
+
+        pub mod b {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[allow(clippy::large_enum_variant)]
+            pub enum Error {
+                #[error("Failed to load config")]
+                Load(#[from] a::Ec),
+                #[error("Failed to fetch")]
+                Fetch(#[from] eyre::Report),
+            }
+
+            pub fn load() -> Result<(), Ec> {
+                a::parse()?;
+                Ok(())
+            }
+
+            pub fn fetch() -> Result<(), Ec> {
+                std::result::Result::Err(eyre::eyre!("Connection refused"))?;
+                Ok(())
+            }
+        }
+
+        pub fn run() -> eyre::Result<()> {
+            b::load()?;
+            eyre::Ok(())
+        }
+
+        pub fn run_converted() -> eyre::Result<()> {
+            use eyre::WrapErr;
+            #[cfg(feature = "nightly")]
+            b::load().into_report().wrap_err("Failed to start")?;
+            #[cfg(not(feature = "nightly"))]
+            b::load()
+                .map_err(IntoReport::into_report)
+                .wrap_err("Failed to start")?;
+            eyre::Ok(())
+        }
+    }
+
+    errore::interop::eyre::install().unwrap();
+
+    // Reports of errors with a trace are formatted by the errore formatter.
+    let report = x::run().unwrap_err();
+    #[cfg(feature = "nightly")]
+    assert_eq_text!(
+        format!("{:?}", report),
+        "
+Error: eyre::Report
+├─▶ <errore::a::Eof> Unexpected end of file
+│   ╰╴ tests/test_eyre.rs:22:17
+│
+╰─▶ <errore::b::Load> Failed to load config
+    ├╴ tests/test_eyre.rs:39:17
+    ╰╴ tests/test_eyre.rs:50:13"
+    );
+    // Without the `nightly` feature the trace of a span can't be requested and the chain is listed.
+    #[cfg(not(feature = "nightly"))]
+    assert_eq_text!(
+        format!("{:?}", report),
+        "
+errore::b::Load: Failed to load config
+    at tests/test_eyre.rs:22:17
+
+Caused by:
+    0: errore::b::Load: Failed to load config
+    at tests/test_eyre.rs:22:17"
+    );
+
+    // Context lines of converted errors are replaced by the trace.
+    let report = x::run_converted().unwrap_err();
+    assert_eq_text!(report.to_string(), "Failed to start");
+    assert_eq_text!(
+        format!("{:?}", report),
+        "
+Failed to start
+Error: errore::b::Load
+├─▶ <errore::a::Eof> Unexpected end of file
+│   ╰╴ tests/test_eyre.rs:22:17
+│
+╰─▶ <errore::b::Load> Failed to load config
+    ╰╴ tests/test_eyre.rs:39:17"
+    );
+    let traced = report
+        .downcast_ref::<errore::interop::TracedError>()
+        .unwrap();
+    assert!(traced.trace().has::<x::a::Error>());
+
+    // Errors without a trace are listed.
+    let report = eyre::eyre!("Connection refused").wrap_err("Request failed");
+    assert_eq_text!(
+        format!("{:?}", report),
+        "
+Request failed
+
+Caused by:
+    0: Connection refused"
+    );
+
+    let ec = x::b::fetch().unwrap_err();
+    assert!(matches!(ec.error(), x::b::Error::Fetch(_)));
+    assert_eq_text!(
+        std::error::Error::source(ec.error()).unwrap().to_string(),
+        "Connection refused"
+    );
+}
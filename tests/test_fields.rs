@@ -0,0 +1,119 @@
+use std::fmt;
+
+use errore::prelude::*;
+use test_utils::*;
+
+#[derive(Default)]
+struct Fields(Vec<String>);
+
+impl FieldVisitor for Fields {
+    fn record_debug(&mut self, name: &str, value: &dyn fmt::Debug) {
+        self.0.push(format!("{}: debug {:?}", name, value));
+    }
+
+    fn record_str(&mut self, name: &str, value: &str) {
+        self.0.push(format!("{}: str {}", name, value));
+    }
+
+    fn record_bool(&mut self, name: &str, value: bool) {
+        self.0.push(format!("{}: bool {}", name, value));
+    }
+
+    fn record_i64(&mut self, name: &str, value: i64) {
+        self.0.push(format!("{}: i64 {}", name, value));
+    }
+
+    fn record_u64(&mut self, name: &str, value: u64) {
+        self.0.push(format!("{}: u64 {}", name, value));
+    }
+
+    fn record_f64(&mut self, name: &str, value: f64) {
+        self.0.push(format!("{}: f64 {}", name, value));
+    }
+}
+
+pub mod x {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("Tag is missing in line {number}")]
+        NoTag {
+            line: String,
+            number: usize,
+            offset: i32,
+            ratio: f32,
+            closed: bool,
+            r#type: &'static str,
+            tags: Vec<String>,
+        },
+        #[error("Invalid token {1}")]
+        InvalidToken(#[redact] String, u8),
+        #[error("No header was found")]
+        NoHeader,
+    }
+
+    #[derive(Error, Debug)]
+    #[errore(context = ParseEc)]
+    #[error("Failed to parse")]
+    pub struct ParseError {
+        pub path: Option<String>,
+        #[source]
+        pub source: Ec,
+    }
+
+    pub fn parse() -> Result<(), ParseEc> {
+        let source = Ec::new(Error::NoHeader);
+        err!(
+            ParseEc,
+            ParseError {
+                path: Some("index.html".into()),
+                source
+            }
+        )
+    }
+}
+
+#[test]
+fn test_visit_fields() {
+    let ec = x::Ec::new(x::Error::NoTag {
+        line: "<div>".into(),
+        number: 3,
+        offset: -1,
+        ratio: 0.5,
+        closed: false,
+        r#type: "div",
+        tags: vec!["div".into()],
+    });
+    let mut fields = Fields::default();
+    ec.visit_fields(&mut fields);
+    assert_eq_text!(
+        fields.0.join("\n"),
+        r#"line: str <div>
+number: u64 3
+offset: i64 -1
+ratio: f64 0.5
+closed: bool false
+type: str div
+tags: debug ["div"]"#
+    );
+
+    let ec = x::Ec::new(x::Error::InvalidToken("secret".into(), 7));
+    let mut fields = Fields::default();
+    ec.error().visit_fields(&mut fields);
+    assert_eq_text!(fields.0.join("\n"), "0: str ***\n1: u64 7");
+
+    let mut fields = Fields::default();
+    x::Error::NoHeader.visit_fields(&mut fields);
+    assert!(fields.0.is_empty());
+}
+
+#[test]
+fn test_visit_fields_record() {
+    let ec = x::parse().unwrap_err();
+    let mut fields = Fields::default();
+    ec.trace().last().visit_fields(&mut fields);
+    assert_eq!(fields.0.len(), 2);
+    assert_eq!(fields.0[0], r#"path: debug Some("index.html")"#);
+    assert!(fields.0[1].starts_with("source: debug Ec(Span {"));
+}
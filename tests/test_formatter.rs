@@ -4,8 +4,6 @@ use errore::formatter::Formatter;
 use errore::*;
 use test_utils::*;
 
-use crate::formatter::ErrorFormatter;
-
 #[derive(Clone, Debug, Default)]
 pub struct TestFormatter;
 
@@ -59,6 +57,7 @@ fn test_register_formatter() {
         "format_trace(): format_record(): errore::test_formatter::Error"
     );
 
-    // overwrite formatter
-    errore::formatter!(ErrorFormatter);
+    // overwrite formatter, with `ctor` every formatter is registered at startup
+    #[cfg(not(feature = "ctor"))]
+    errore::formatter!(errore::formatter::ErrorFormatter);
 }
@@ -110,3 +110,65 @@ fn test_from_many() {
     assert_from_stderror::<Ec>();
     assert_from_error::<Ec>();
 }
+
+#[test]
+fn test_from_with() {
+    use std::error::Error as _;
+
+    #[derive(Debug, PartialEq)]
+    pub enum Status {
+        NotFound,
+        Unknown(u16),
+    }
+
+    impl Status {
+        fn from_code(code: u16) -> Self {
+            match code {
+                404 => Status::NotFound,
+                code => Status::Unknown(code),
+            }
+        }
+    }
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("{0}")]
+        Message(#[from(via = &'static str, with = str::to_owned)] String),
+        #[error("Request failed with {0:?}")]
+        Status(#[from(via = u16, with = Status::from_code)] Status),
+        #[error("Exit code {0}")]
+        ExitCode(#[from(via = u8)] i32),
+    }
+
+    fn assert_from<T: From<&'static str> + From<u16> + From<u8>>() {}
+    assert_from::<Error>();
+    assert_from::<Ec>();
+
+    let ec = Ec::from("Invalid input");
+    assert!(matches!(ec.error(), Error::Message(msg) if msg == "Invalid input"));
+    assert!(ec.error().source().is_none());
+
+    let ec = Ec::from(404u16);
+    assert!(matches!(ec.error(), Error::Status(Status::NotFound)));
+    assert_eq!(ec.error().to_string(), "Request failed with NotFound");
+
+    let ec = Ec::from(2u8);
+    assert!(matches!(ec.error(), Error::ExitCode(2)));
+}
+
+#[test]
+fn test_from_with_location() {
+    #[derive(Error, Debug)]
+    #[error("{0}")]
+    pub struct Error(#[from(with = String::from)] String);
+
+    fn validate() -> Result<(), Ec> {
+        core::result::Result::<(), String>::Err("Invalid input".into())?;
+        Ok(())
+    }
+
+    let ec = validate().unwrap_err();
+    assert_eq!(ec.error().0, "Invalid input");
+    assert_eq!(ec.trace().len(), 1);
+    assert_eq!(ec.trace().last().location.line(), 166);
+}
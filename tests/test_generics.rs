@@ -49,9 +49,7 @@ fn test_display_enum_compound() {
 
     impl std::error::Error for DebugAndDisplay {}
 
-    let instance: c::EnumDisplay<DebugAndDisplay>;
-
-    instance = c::EnumDisplay::DisplayDebug(DebugAndDisplay);
+    let instance: c::EnumDisplay<DebugAndDisplay> = c::EnumDisplay::DisplayDebug(DebugAndDisplay);
     assert_eq!(format!("{}", instance), "debug and display DebugAndDisplay");
 }
 
@@ -0,0 +1,186 @@
+#![cfg(feature = "tonic")]
+#![allow(clippy::large_enum_variant, clippy::result_large_err)]
+
+use std::convert::Infallible;
+use std::fmt;
+use std::future::{ready, Ready};
+use std::net::SocketAddr;
+use std::task::{Context, Poll};
+
+use errore::*;
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::body::Body;
+use tonic::codegen::{http, BoxFuture, Service};
+use tonic::server::{NamedService, UnaryService};
+use tonic::transport::{Endpoint, Server};
+use tonic::{Code, Status};
+use tonic_prost::ProstCodec;
+use tonic_types::StatusExt;
+
+pub mod account {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    #[errore(grpc = "NOT_FOUND")]
+    pub enum Error {
+        #[error("Account '{user}' not found")]
+        NotFound { user: String, attempts: u32 },
+        #[error("Account '{0}' is locked")]
+        #[errore(grpc = "PERMISSION_DENIED")]
+        Locked(String),
+    }
+}
+
+pub mod api {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error(transparent)]
+        Account(#[from] account::Ec),
+        #[error("Service unavailable")]
+        #[errore(grpc = "UNAVAILABLE")]
+        Unavailable(#[from] account::Error),
+        #[error("Internal error")]
+        Internal,
+    }
+
+    pub fn get(error: account::Error) -> core::result::Result<(), Ec> {
+        core::result::Result::Err(account::Ec::new(error))?;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct Fields(Vec<String>);
+
+impl FieldVisitor for Fields {
+    fn record_debug(&mut self, name: &str, value: &dyn fmt::Debug) {
+        self.0.push(format!("{}: {:?}", name, value));
+    }
+}
+
+/// Fails every request with the status of the account error.
+#[derive(Clone)]
+struct AccountService;
+
+impl NamedService for AccountService {
+    const NAME: &'static str = "test.Account";
+}
+
+struct Get;
+
+impl UnaryService<()> for Get {
+    type Response = ();
+    type Future = Ready<Result<tonic::Response<()>, Status>>;
+
+    fn call(&mut self, _request: tonic::Request<()>) -> Self::Future {
+        let error = api::get(account::Error::NotFound {
+            user: "root".into(),
+            attempts: 3,
+        })
+        .unwrap_err();
+        ready(Err(Status::from(error)))
+    }
+}
+
+impl Service<http::Request<Body>> for AccountService {
+    type Response = http::Response<Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<Body>) -> Self::Future {
+        Box::pin(async move {
+            let mut grpc = tonic::server::Grpc::new(ProstCodec::<(), ()>::default());
+            Ok(grpc.unary(Get, req).await)
+        })
+    }
+}
+
+async fn serve() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(
+        Server::builder()
+            .add_service(AccountService)
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+    addr
+}
+
+async fn request(addr: SocketAddr) -> Status {
+    let channel = Endpoint::new(format!("http://{addr}"))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = tonic::client::Grpc::new(channel);
+    client.ready().await.unwrap();
+    client
+        .unary(
+            tonic::Request::new(()),
+            http::uri::PathAndQuery::from_static("/test.Account/Get"),
+            ProstCodec::<(), ()>::default(),
+        )
+        .await
+        .unwrap_err()
+}
+
+#[test]
+fn test_grpc_code() {
+    let ec = account::Ec::new(account::Error::Locked("root".into()));
+    assert_eq!(ec.grpc_code(), Some("PERMISSION_DENIED"));
+    assert_eq!(ec.trace().grpc_code(), Some("PERMISSION_DENIED"));
+    assert_eq!(api::Error::Internal.grpc_code(), None);
+
+    // transparent errors inherit the code of the inner error
+    let ec = api::get(account::Error::Locked("root".into())).unwrap_err();
+    assert_eq!(ec.trace().grpc_code(), Some("PERMISSION_DENIED"));
+
+    let status = Status::from(api::Ec::new(api::Error::Unavailable(
+        account::Error::Locked("root".into()),
+    )));
+    assert_eq!(status.code(), Code::Unavailable);
+    assert_eq!(status.message(), "Service unavailable");
+
+    let status = Status::from(api::Ec::new(api::Error::Internal));
+    assert_eq!(status.code(), Code::Unknown);
+    let info = status.get_details_error_info().unwrap();
+    assert_eq!(info.reason, "errore::api::Internal");
+    assert_eq!(info.domain, "test_grpc");
+    assert!(info.metadata.is_empty());
+}
+
+#[tokio::test]
+async fn test_grpc_loopback() {
+    let addr = serve().await;
+    let status = request(addr).await;
+
+    assert_eq!(status.code(), Code::NotFound);
+    assert_eq!(status.message(), "Account 'root' not found");
+    let info = status.get_details_error_info().unwrap();
+    assert_eq!(info.reason, "errore::account::NotFound");
+    assert_eq!(info.domain, "test_grpc");
+    assert_eq!(info.metadata["user"], "root");
+    assert_eq!(info.metadata["attempts"], "3");
+
+    let line = line!() + 1;
+    let error = RemoteError::from(status);
+    let rec = error.trace().last();
+    assert_eq!(rec.name, "errore::account::NotFound");
+    assert_eq!(rec.target, "test_grpc");
+    assert_eq!(rec.location.file(), "tests/test_grpc.rs");
+    assert_eq!(rec.location.line(), line);
+    assert_eq!(rec.grpc_code(), Some("NOT_FOUND"));
+    assert_eq!(error.trace().grpc_code(), Some("NOT_FOUND"));
+    assert_eq!(error.to_string(), "Account 'root' not found");
+
+    let mut fields = Fields::default();
+    rec.visit_fields(&mut fields);
+    assert_eq!(fields.0, ["attempts: \"3\"", "user: \"root\""]);
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use errore::prelude::*;
+
+#[test]
+fn test_kind() {
+    pub mod x {
+        use super::*;
+
+        #[derive(Error, Debug)]
+        #[errore(kind)]
+        pub enum Error {
+            #[error("Invalid email or password")]
+            InvalidCredentials,
+            #[error("Account '{0}' is locked")]
+            Locked(String),
+            #[error("Too many attempts: {attempts}")]
+            RateLimited { attempts: u32 },
+        }
+    }
+
+    let ec = x::Ec::new(x::Error::Locked("root".into()));
+    assert_eq!(ec.kind(), x::ErrorKind::Locked);
+    assert_eq!(ec.error().kind(), x::ErrorKind::Locked);
+    assert_ne!(ec.kind(), x::ErrorKind::InvalidCredentials);
+    assert_eq!(
+        x::Error::RateLimited { attempts: 3 }.kind(),
+        x::ErrorKind::RateLimited
+    );
+
+    // The kind outlives the error.
+    let kind = ec.kind();
+    drop(ec);
+    let handle = std::thread::spawn(move || kind);
+    assert_eq!(handle.join().unwrap(), x::ErrorKind::Locked);
+
+    let mut counter = HashMap::<x::ErrorKind, usize>::new();
+    for error in [
+        x::Error::InvalidCredentials,
+        x::Error::Locked("root".into()),
+        x::Error::InvalidCredentials,
+    ] {
+        *counter.entry(error.kind()).or_default() += 1;
+    }
+    assert_eq!(counter[&x::ErrorKind::InvalidCredentials], 2);
+    assert_eq!(counter[&x::ErrorKind::Locked], 1);
+    assert!(!counter.contains_key(&x::ErrorKind::RateLimited));
+}
+
+#[test]
+fn test_kind_id() {
+    pub mod x {
+        use super::*;
+
+        pub mod a {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[errore(kind = ReadKind)]
+            pub enum Error {
+                #[error("Not found")]
+                NotFound,
+                #[error("Permission denied")]
+                PermissionDenied,
+            }
+
+            pub fn read() -> Result<(), Ec> {
+                err!(Error::PermissionDenied)
+            }
+        }
+
+        pub mod b {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[errore(kind)]
+            pub enum Error {
+                #[error(transparent)]
+                Read(#[from] a::Ec),
+            }
+
+            pub fn load() -> Result<(), Ec> {
+                a::read()?;
+                Ok(())
+            }
+        }
+    }
+
+    let ec = x::b::load().unwrap_err();
+    assert_eq!(ec.kind(), x::b::ErrorKind::Read);
+    assert_eq!(ec.trace().first().id, x::a::ReadKind::PermissionDenied.id());
+    assert_ne!(ec.trace().first().id, x::a::ReadKind::NotFound.id());
+    assert_eq!(ec.trace().last().id, x::b::ErrorKind::Read.id());
+    assert_eq!(*ec.id(), x::b::ErrorKind::Read.id());
+    assert!(ec
+        .trace()
+        .iter()
+        .any(|tr| tr.id == x::a::ReadKind::PermissionDenied.id()));
+}
+
+#[test]
+fn test_kind_opt_in() {
+    pub mod x {
+        use std::io::ErrorKind;
+
+        use super::*;
+
+        #[derive(Error, Debug)]
+        pub enum Error {
+            #[error("Not found")]
+            NotFound,
+        }
+
+        pub fn kind() -> ErrorKind {
+            ErrorKind::NotFound
+        }
+    }
+
+    let ec = x::Ec::new(x::Error::NotFound);
+    assert_eq!(ec.trace().last().id, *ec.id());
+    assert_eq!(x::kind(), std::io::ErrorKind::NotFound);
+}
@@ -134,3 +134,382 @@ fn test_enum_metadata_is_transparent() {
     let ec = x::b::Ec::new(x::b::ErrorTransparent::Field(ec));
     assert!(ec.is_transparent());
 }
+
+#[test]
+fn test_metadata_code() {
+    pub mod x {
+        use super::*;
+
+        pub mod a {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[error("Invalid credentials for {0}")]
+            #[errore(code = "AUTH-0042")]
+            pub struct ErrorStruct(pub String);
+
+            pub fn authenticate() -> Result<(), Ec> {
+                err!(ErrorStruct("admin".into()))
+            }
+        }
+
+        pub mod b {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[allow(clippy::large_enum_variant)]
+            pub enum ErrorEnum {
+                #[error("Value {} is out of range {}", value, 10)]
+                #[errore(code = 42)]
+                Range { value: usize },
+                #[error("Session expired")]
+                #[errore(code = "AUTH-0043")]
+                Expired,
+                #[error(transparent)]
+                #[errore(code = "AUTH-0044")]
+                Auth(#[from] a::Ec),
+                #[error("...")]
+                Uncoded,
+            }
+
+            pub fn login() -> Result<(), Ec> {
+                a::authenticate()?;
+                Ok(())
+            }
+        }
+    }
+
+    let ec = x::a::Ec::new(x::a::ErrorStruct("root".into()));
+    assert_eq!(ec.code(), Some("AUTH-0042"));
+    assert_eq!(ec.error().code(), Some("AUTH-0042"));
+    assert_eq!(ec.trace().last().code.as_deref(), Some("AUTH-0042"));
+    assert_eq_text!(
+        ec.to_string(),
+        "
+errore::a::ErrorStruct [AUTH-0042]: Invalid credentials for root
+    at tests/test_metadata.rs:182:14"
+    );
+
+    let ec = x::b::Ec::new(x::b::ErrorEnum::Range { value: 11 });
+    assert_eq!(ec.code(), Some("42"));
+    assert_eq_text!(ec.error().to_string(), "Value 11 is out of range 10");
+    assert_eq!(x::b::ErrorEnum::Expired.code(), Some("AUTH-0043"));
+    assert_eq!(x::b::ErrorEnum::Uncoded.code(), None);
+
+    let ec = x::b::login().unwrap_err();
+    assert_eq!(ec.code(), Some("AUTH-0044"));
+    assert_eq_text!(
+        ec.trace().to_string(),
+        "
+Error: errore::b::Auth [AUTH-0044]
+├─▶ <errore::a::ErrorStruct> [AUTH-0042] Invalid credentials for admin
+│   ╰╴ tests/test_metadata.rs:152:17
+│
+╰─▶ <errore::b::Auth> [AUTH-0044]
+    ╰╴ tests/test_metadata.rs:176:17"
+    );
+}
+
+#[test]
+fn test_metadata_severity() {
+    pub mod x {
+        use super::*;
+
+        pub mod a {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[error("Cache miss")]
+            #[errore(severity = "warn")]
+            pub struct ErrorStruct;
+
+            pub fn lookup() -> Result<(), Ec> {
+                err!(ErrorStruct)
+            }
+        }
+
+        pub mod b {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[errore(severity = "fatal")]
+            #[allow(clippy::large_enum_variant)]
+            pub enum ErrorEnum {
+                #[error("Retrying")]
+                #[errore(severity = "warn")]
+                Retry,
+                #[error("Database is corrupted")]
+                Corrupted,
+                #[error(transparent)]
+                Lookup(#[from] a::Ec),
+            }
+
+            pub fn load() -> Result<(), Ec> {
+                a::lookup()?;
+                Ok(())
+            }
+        }
+
+        pub mod c {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            pub enum ErrorEnum {
+                #[error("Failed to start")]
+                Start(#[from] b::Ec),
+            }
+
+            pub fn start() -> Result<(), Ec> {
+                b::load()?;
+                Ok(())
+            }
+        }
+    }
+
+    assert_eq!(errore::Severity::default(), errore::Severity::Error);
+    assert!(errore::Severity::Warn < errore::Severity::Error);
+    assert!(errore::Severity::Error < errore::Severity::Fatal);
+    assert_eq_text!(errore::Severity::Fatal.to_string(), "fatal");
+
+    assert_eq!(x::b::ErrorEnum::Retry.severity(), errore::Severity::Warn);
+    assert_eq!(
+        x::b::ErrorEnum::Corrupted.severity(),
+        errore::Severity::Fatal
+    );
+
+    let ec = x::a::Ec::new(x::a::ErrorStruct);
+    assert_eq!(ec.severity(), errore::Severity::Warn);
+    assert_eq!(ec.trace().severity(), errore::Severity::Warn);
+    assert!(ec
+        .trace()
+        .to_string()
+        .starts_with("Warning: errore::a::ErrorStruct\n"));
+
+    let ec = x::b::Ec::new(x::b::ErrorEnum::Corrupted);
+    assert_eq!(ec.trace().last().severity(), errore::Severity::Fatal);
+    assert!(ec
+        .trace()
+        .to_string()
+        .starts_with("Fatal: errore::b::Corrupted\n"));
+
+    // Transparent variants forward the severity of the wrapped error.
+    let ec = x::b::load().unwrap_err();
+    assert_eq!(ec.severity(), errore::Severity::Warn);
+    assert_eq!(ec.trace().last().severity(), errore::Severity::Warn);
+    assert_eq!(ec.trace().severity(), errore::Severity::Warn);
+    assert!(ec
+        .trace()
+        .to_string()
+        .starts_with("Warning: errore::b::Lookup\n"));
+
+    // The trace reports the highest severity of all records.
+    let ec = x::c::start().unwrap_err();
+    assert_eq!(ec.severity(), errore::Severity::Error);
+    assert_eq!(ec.trace().first().severity(), errore::Severity::Warn);
+    assert_eq!(ec.trace().severity(), errore::Severity::Error);
+    assert!(ec
+        .trace()
+        .to_string()
+        .starts_with("Error: errore::c::Start\n"));
+}
+
+#[test]
+fn test_metadata_help() {
+    pub mod x {
+        use super::*;
+
+        pub mod a {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[error("Session of '{user}' expired")]
+            #[errore(help = "try running `login {user}` first")]
+            pub struct ErrorStruct {
+                pub user: String,
+            }
+
+            pub fn auth() -> Result<(), Ec> {
+                err!(ErrorStruct {
+                    user: "root".into()
+                })
+            }
+        }
+
+        pub mod b {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            #[errore(note = "see the documentation for details")]
+            #[allow(clippy::large_enum_variant)]
+            pub enum ErrorEnum {
+                #[error("Invalid port {0}")]
+                #[errore(help = "use a port between 1 and 65535, not {0}")]
+                Port(u32),
+                #[error("Unknown key '{0}'")]
+                #[errore(
+                    help = "remove the key '{0}'\nor rename it",
+                    note = "keys are case sensitive"
+                )]
+                Key(String),
+                #[error(transparent)]
+                Auth(#[from] a::Ec),
+            }
+
+            pub fn connect() -> Result<(), Ec> {
+                a::auth()?;
+                Ok(())
+            }
+        }
+    }
+
+    let error = x::a::ErrorStruct {
+        user: "admin".into(),
+    };
+    assert_eq!(
+        error.help().as_deref(),
+        Some("try running `login admin` first")
+    );
+    assert!(error.note().is_none());
+
+    // The enum keys are used as default for every variant.
+    let error = x::b::ErrorEnum::Key("port".into());
+    assert_eq!(
+        error.help().as_deref(),
+        Some("remove the key 'port'\nor rename it")
+    );
+    assert_eq!(error.note().as_deref(), Some("keys are case sensitive"));
+    let error = x::b::ErrorEnum::Port(0);
+    assert_eq!(
+        error.help().as_deref(),
+        Some("use a port between 1 and 65535, not 0")
+    );
+    assert_eq!(
+        error.note().as_deref(),
+        Some("see the documentation for details")
+    );
+
+    let ec = x::b::connect().unwrap_err();
+    assert_eq!(
+        ec.trace().first().help().as_deref(),
+        Some("try running `login root` first")
+    );
+    assert_eq_text!(
+        ec.trace().to_string(),
+        "
+Error: errore::b::Auth
+├─▶ <errore::a::ErrorStruct> Session of 'root' expired
+│   = help: try running `login root` first
+│   ╰╴ tests/test_metadata.rs:332:17
+│
+╰─▶ <errore::b::Auth>
+    = note: see the documentation for details
+    ╰╴ tests/test_metadata.rs:359:17"
+    );
+
+    let ec = x::b::Ec::new(x::b::ErrorEnum::Key("port".into()));
+    assert_eq_text!(
+        ec.trace().to_string(),
+        "
+Error: errore::b::Key
+╰─▶ <errore::b::Key> Unknown key 'port'
+    = help: remove the key 'port'
+            or rename it
+    = note: keys are case sensitive
+    ╰╴ tests/test_metadata.rs:409:14"
+    );
+}
+
+#[test]
+fn test_metadata_name_target() {
+    pub mod x {
+        pub mod a {
+            pub mod error {
+                use errore::prelude::*;
+
+                #[derive(Error, Debug)]
+                #[errore(name = "db", target = "storage", kind)]
+                pub enum Error {
+                    #[error("I/O failure")]
+                    Io,
+                    #[errore(name = "db::timeout", target = "network")]
+                    #[error("Timed out")]
+                    Timeout,
+                }
+            }
+        }
+
+        pub mod b {
+            pub mod error {
+                use errore::prelude::*;
+
+                #[derive(Error, Debug)]
+                pub enum Error {
+                    #[error("I/O failure")]
+                    Io,
+                }
+
+                #[derive(Error, Debug)]
+                #[errore(context = ConfigEc, name = "config::Invalid")]
+                #[error("Invalid configuration")]
+                pub struct ConfigError;
+            }
+        }
+    }
+
+    let ec = x::a::error::Ec::new(x::a::error::Error::Io);
+    assert_eq_text!(ec.name(), "db::Io");
+    assert_eq_text!(ec.target(), "storage");
+    assert_eq!(*ec.target_id(), Id::from_target("storage"));
+    assert_eq_text!(&ec.trace().last().name, "db::Io");
+    assert_eq_text!(&ec.trace().last().target, "storage");
+    assert_eq!(ec.kind().id(), *ec.id());
+
+    let ec = x::a::error::Ec::new(x::a::error::Error::Timeout);
+    assert_eq_text!(ec.name(), "db::timeout");
+    assert_eq_text!(ec.target(), "network");
+    assert_eq!(*ec.target_id(), Id::from_target("network"));
+
+    // Errors with the same module name don't collide anymore.
+    let other = x::b::error::Ec::new(x::b::error::Error::Io);
+    assert_eq_text!(other.name(), "errore::error::Io");
+    assert_eq_text!(other.target(), "test_metadata");
+    assert_ne!(
+        *x::a::error::Ec::new(x::a::error::Error::Io).id(),
+        *other.id()
+    );
+
+    let ec = x::b::error::ConfigEc::new(x::b::error::ConfigError);
+    assert_eq_text!(ec.name(), "config::Invalid");
+    assert_eq_text!(ec.target(), "test_metadata");
+    assert_eq!(*ec.target_id(), Id::from_target("test_metadata"));
+}
+
+#[test]
+fn test_metadata_named_args() {
+    #[derive(Error, Debug)]
+    #[error(
+        "{code} {severity} {status} {grpc} {help} {note}",
+        code = self.code,
+        severity = "low",
+        status = 404,
+        grpc = "NOT_FOUND",
+        help = self.help,
+        note = "none",
+    )]
+    #[errore(severity = "warn")]
+    pub struct ErrorStruct {
+        code: u32,
+        help: &'static str,
+    }
+
+    let error = ErrorStruct {
+        code: 42,
+        help: "retry",
+    };
+    assert_eq_text!(error.to_string(), "42 low 404 NOT_FOUND retry none");
+    assert_eq!(error.code(), None);
+    assert_eq!(error.severity(), errore::Severity::Warn);
+    assert_eq!(error.status(), None);
+    assert_eq!(error.grpc_code(), None);
+    assert_eq!(error.help(), None);
+}
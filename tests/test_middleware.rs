@@ -0,0 +1,123 @@
+#![cfg(feature = "tower")]
+
+use std::future::{ready, Future, Ready};
+use std::pin::pin;
+use std::sync::{Mutex, Once};
+use std::task::{Context, Poll, Waker};
+
+use errore::middleware::{ReportLayer, RequestContext};
+use errore::subscriber::Subscriber;
+use errore::*;
+use http::header::HeaderName;
+use http::Request;
+use tower_layer::Layer;
+use tower_service::Service;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Account '{0}' is locked")]
+    Locked(String),
+}
+
+static REPORTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+static INIT: Once = Once::new();
+
+struct ReportSubscriber;
+
+impl Subscriber for ReportSubscriber {
+    fn on_request_error(&self, ctx: &mut TraceContext, req: &RequestContext) {
+        assert!(ctx.extensions().get::<RequestContext>().is_some());
+        REPORTS.lock().unwrap().push(format!(
+            "request_error: {} {} {:?}",
+            req.method, req.uri, req.request_id
+        ));
+    }
+
+    fn on_end(&self, ctx: &mut TraceContext) {
+        if let Some(req) = ctx.extensions().get::<RequestContext>() {
+            REPORTS.lock().unwrap().push(format!(
+                "end: {} {} {}",
+                req.method,
+                req.uri,
+                ctx.last().name
+            ));
+        }
+    }
+}
+
+/// Fails for every request to `/login`.
+struct Login;
+
+impl Service<Request<()>> for Login {
+    type Response = &'static str;
+    type Error = Ec;
+    type Future = Ready<Result<&'static str, Ec>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Ec>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<()>) -> Self::Future {
+        if req.uri() == "/login" {
+            ready(Err(Ec::new(Error::Locked("root".into()))))
+        } else {
+            ready(Ok("ok"))
+        }
+    }
+}
+
+fn call<S>(service: &mut S, req: Request<()>) -> Result<S::Response, S::Error>
+where
+    S: Service<Request<()>>,
+{
+    let future = pin!(service.call(req));
+    match future.poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(result) => result,
+        Poll::Pending => unreachable!(),
+    }
+}
+
+fn request(uri: &str, header: &str) -> Request<()> {
+    Request::post(uri).header(header, "42").body(()).unwrap()
+}
+
+#[test]
+fn test_report_layer() {
+    INIT.call_once(|| {
+        errore::subscriber!(ReportSubscriber);
+    });
+
+    let mut service = ReportLayer::new().layer(Login);
+    assert_eq!(
+        call(&mut service, request("/", "x-request-id")).unwrap(),
+        "ok"
+    );
+    assert!(REPORTS.lock().unwrap().is_empty());
+
+    let ec = call(&mut service, request("/login", "x-request-id")).unwrap_err();
+    let req = ec
+        .trace()
+        .extensions()
+        .get::<RequestContext>()
+        .cloned()
+        .unwrap();
+    assert_eq!(req.method, "POST");
+    assert_eq!(req.uri, "/login");
+    assert_eq!(req.request_id.as_deref(), Some("42"));
+    drop(ec);
+
+    let mut service = ReportLayer::new()
+        .request_id_header(HeaderName::from_static("x-correlation-id"))
+        .layer(Login);
+    drop(call(&mut service, request("/login", "x-request-id")).unwrap_err());
+
+    assert_eq!(
+        *REPORTS.lock().unwrap(),
+        [
+            "request_error: POST /login Some(\"42\")",
+            "end: POST /login errore::test_middleware::Locked",
+            "request_error: POST /login None",
+            "end: POST /login errore::test_middleware::Locked",
+        ]
+    );
+}
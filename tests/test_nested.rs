@@ -0,0 +1,182 @@
+use std::error::Error as _;
+
+use errore::prelude::*;
+use test_utils::*;
+
+#[test]
+fn test_nested_box_self() {
+    #[derive(Error, Debug)]
+    #[errore(kind)]
+    pub enum Error {
+        #[error("Division by zero")]
+        DivisionByZero,
+        #[error("Failed to evaluate operand")]
+        Operand(#[source] Box<Error>),
+    }
+
+    let ec = Ec::new(Error::Operand(Box::new(Error::Operand(Box::new(
+        Error::DivisionByZero,
+    )))));
+    assert_eq!(ec.kind(), ErrorKind::Operand);
+
+    let source = ec.error().source().unwrap();
+    assert_eq_text!(source.to_string(), "Failed to evaluate operand");
+    let source = source.source().unwrap();
+    assert_eq_text!(source.to_string(), "Division by zero");
+    assert!(source.source().is_none());
+}
+
+#[test]
+fn test_nested_ec() {
+    pub mod x {
+        use super::*;
+
+        pub mod a {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            pub enum Error {
+                #[error("Unknown variable '{0}'")]
+                UnknownVariable(String),
+            }
+
+            pub fn resolve(name: &str) -> Result<(), Ec> {
+                err!(Error::UnknownVariable(name.into()))
+            }
+        }
+
+        pub mod b {
+            use super::*;
+
+            #[derive(Error, Debug)]
+            pub enum Error {
+                #[error("Failed to call '{name}'")]
+                Call {
+                    name: String,
+                    #[source]
+                    inner: Box<a::Ec>,
+                },
+                #[error("Failed to evaluate {} arguments", .0.len())]
+                Arguments(#[source] Vec<a::Ec>),
+                #[error("Failed to evaluate block")]
+                Block(Option<Box<Ec>>),
+            }
+
+            pub fn call() -> Result<(), Ec> {
+                let inner = a::resolve("x").unwrap_err();
+                err!(Error::Call {
+                    name: "sum".into(),
+                    inner: Box::new(inner),
+                })
+            }
+
+            pub fn arguments() -> Result<(), Ec> {
+                let errors = ["x", "y"]
+                    .iter()
+                    .filter_map(|name| a::resolve(name).err())
+                    .collect();
+                err!(Error::Arguments(errors))
+            }
+
+            pub fn block() -> Result<(), Ec> {
+                err!(Error::Block(Some(Box::new(arguments().unwrap_err()))))
+            }
+        }
+    }
+
+    let ec = x::b::call().unwrap_err();
+    assert_eq!(ec.trace().len(), 1);
+    assert_eq_text!(
+        ec.error().source().unwrap().to_string(),
+        "
+errore::a::UnknownVariable: Unknown variable 'x'
+    at tests/test_nested.rs:44:17"
+    );
+    assert!(ec.has::<x::a::Error>());
+    assert!(matches!(
+        &*ec.get::<x::a::Error>().unwrap(),
+        x::a::Error::UnknownVariable(name) if name == "x"
+    ));
+    assert_eq_text!(
+        ec.trace().to_string(),
+        "
+Error: errore::b::Call
+╰─▶ <errore::b::Call> Failed to call 'sum'
+    ├── Error: errore::a::UnknownVariable
+    │   ╰─▶ <errore::a::UnknownVariable> Unknown variable 'x'
+    │       ╰╴ tests/test_nested.rs:44:17
+    ╰╴ tests/test_nested.rs:67:17"
+    );
+
+    // The first nested error is used as source.
+    let ec = x::b::arguments().unwrap_err();
+    assert_eq_text!(
+        ec.error().source().unwrap().to_string(),
+        "
+errore::a::UnknownVariable: Unknown variable 'x'
+    at tests/test_nested.rs:44:17"
+    );
+    assert_eq_text!(
+        ec.trace().to_string(),
+        "
+Error: errore::b::Arguments
+╰─▶ <errore::b::Arguments> Failed to evaluate 2 arguments
+    ├── Error: errore::a::UnknownVariable
+    │   ╰─▶ <errore::a::UnknownVariable> Unknown variable 'x'
+    │       ╰╴ tests/test_nested.rs:44:17
+    ├── Error: errore::a::UnknownVariable
+    │   ╰─▶ <errore::a::UnknownVariable> Unknown variable 'y'
+    │       ╰╴ tests/test_nested.rs:44:17
+    ╰╴ tests/test_nested.rs:78:17"
+    );
+
+    // Nested contexts are traversed recursively.
+    let ec = x::b::block().unwrap_err();
+    assert!(ec.error().source().is_none());
+    assert!(ec.has::<x::a::Error>());
+    assert!(matches!(
+        &*ec.get::<x::b::Error>().unwrap(),
+        x::b::Error::Block(_)
+    ));
+    assert_eq_text!(
+        ec.trace().to_string(),
+        "
+Error: errore::b::Block
+╰─▶ <errore::b::Block> Failed to evaluate block
+    ├── Error: errore::b::Arguments
+    │   ╰─▶ <errore::b::Arguments> Failed to evaluate 2 arguments
+    │       ├── Error: errore::a::UnknownVariable
+    │       │   ╰─▶ <errore::a::UnknownVariable> Unknown variable 'x'
+    │       │       ╰╴ tests/test_nested.rs:44:17
+    │       ├── Error: errore::a::UnknownVariable
+    │       │   ╰─▶ <errore::a::UnknownVariable> Unknown variable 'y'
+    │       │       ╰╴ tests/test_nested.rs:44:17
+    │       ╰╴ tests/test_nested.rs:78:17
+    ╰╴ tests/test_nested.rs:82:17"
+    );
+}
+
+#[test]
+fn test_nested_struct_fields() {
+    #[derive(Error, Debug)]
+    #[error("Failed to send {} bytes", buffer.len())]
+    pub struct Error {
+        buffer: Vec<u8>,
+        errors: Vec<Ec>,
+        previous: Option<Box<Ec>>,
+    }
+
+    let first = Ec::new(Error {
+        buffer: vec![1],
+        errors: Vec::new(),
+        previous: None,
+    });
+    let ec = Ec::new(Error {
+        buffer: vec![1, 2],
+        errors: vec![first],
+        previous: None,
+    });
+    let mut nested = 0;
+    ec.visit_nested(&mut |_| nested += 1);
+    assert_eq!(nested, 1);
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "serde")]
+
+use errore::prelude::*;
+use serde_json::json;
+
+pub mod auth {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("Account '{user}' is locked")]
+        #[errore(code = "E-LOCK", status = 403)]
+        Locked {
+            user: String,
+            #[redact]
+            password: String,
+            attempts: u32,
+        },
+        #[error("Session expired")]
+        Expired { status: u32 },
+    }
+}
+
+pub mod api {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error(transparent)]
+        Auth(#[from] auth::Ec),
+    }
+
+    pub fn login(error: auth::Error) -> Result<(), Ec> {
+        core::result::Result::Err(auth::Ec::new(error))?;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_problem_details() {
+    let ec = api::login(auth::Error::Locked {
+        user: "root".into(),
+        password: "secret".into(),
+        attempts: 3,
+    })
+    .unwrap_err();
+
+    let problem = ProblemDetails::new(&ec).with_instance("/login");
+    assert_eq!(problem.problem_type(), "E-LOCK");
+    assert_eq!(problem.title(), "Account '{user}' is locked");
+    assert_eq!(problem.status(), Some(403));
+    assert_eq!(problem.detail(), Some("Account 'root' is locked"));
+    assert_eq!(problem.instance(), Some("/login"));
+
+    let value = serde_json::to_value(problem.without_trace()).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "type": "E-LOCK",
+            "title": "Account '{user}' is locked",
+            "status": 403,
+            "detail": "Account 'root' is locked",
+            "instance": "/login",
+            "user": "root",
+            "password": "***",
+            "attempts": 3
+        })
+    );
+}
+
+#[test]
+fn test_problem_details_defaults() {
+    let ec = api::login(auth::Error::Expired { status: 1 }).unwrap_err();
+    let value = serde_json::to_value(ProblemDetails::from(ec.trace()).without_trace()).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "type": "errore::auth::Expired",
+            "title": "Session expired",
+            "detail": "Session expired"
+        })
+    );
+}
+
+#[test]
+fn test_problem_details_trace() {
+    let ec = api::login(auth::Error::Expired { status: 1 }).unwrap_err();
+    let value = serde_json::to_value(ProblemDetails::new(&ec)).unwrap();
+    let trace = value["trace"].as_array().unwrap();
+    assert_eq!(trace.len(), 2);
+    assert_eq!(trace[0]["name"], "errore::auth::Expired");
+    assert_eq!(trace[0]["message"], "Session expired");
+    assert_eq!(trace[0]["location"]["line"], 34);
+    assert_eq!(trace[1]["name"], "errore::api::Auth");
+    assert_eq!(trace[1]["code"], json!(null));
+    assert_eq!(ProblemDetails::CONTENT_TYPE, "application/problem+json");
+}
+
+#[test]
+fn test_problem_details_taken_trace() {
+    let ec = api::login(auth::Error::Locked {
+        user: "root".into(),
+        password: "secret".into(),
+        attempts: 3,
+    })
+    .unwrap_err();
+
+    // the trace of the wrapped context was moved by the conversion
+    let api::Error::Auth(inner) = ec.error();
+    assert!(inner.trace_ref().is_none());
+    let value = serde_json::to_value(ProblemDetails::new(inner)).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "type": "E-LOCK",
+            "title": "Account '{user}' is locked",
+            "status": 403,
+            "detail": "Account 'root' is locked",
+            "user": "root",
+            "password": "***",
+            "attempts": 3
+        })
+    );
+}
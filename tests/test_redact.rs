@@ -0,0 +1,87 @@
+#![allow(clippy::large_enum_variant)]
+
+use errore::prelude::*;
+use test_utils::*;
+
+pub mod x {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("Invalid password for '{email}'")]
+        #[errore(help = "reset the password of '{email}'")]
+        InvalidPassword {
+            #[redact]
+            email: String,
+        },
+        #[error("Invalid token {0:?} ({1})")]
+        InvalidToken(#[redact] String, u32),
+        #[error("Unknown user '{email}' ({}, {:?}, {len})", name, &name, email = email, len = email.len())]
+        UnknownUser {
+            #[redact]
+            email: String,
+            #[redact]
+            name: String,
+        },
+    }
+
+    #[derive(Error, Debug)]
+    #[errore(context = KeyEc)]
+    #[error("Locked key {key:?} ({}, {len})", self.1, key = &self.0, len = self.0.len())]
+    pub struct KeyError(#[redact] pub String, #[redact] pub String);
+
+    #[derive(Error, Debug)]
+    #[errore(context = SessionEc)]
+    #[error("Session {id} of '{user:>8}' expired")]
+    pub struct SessionError {
+        pub id: u32,
+        #[redact]
+        pub user: String,
+    }
+
+    pub fn login() -> Result<(), Ec> {
+        err!(Error::InvalidPassword {
+            email: "root@localhost".into()
+        })
+    }
+}
+
+#[test]
+fn test_redact() {
+    let ec = x::login().unwrap_err();
+    assert_eq_text!(ec.error().to_string(), "Invalid password for '***'");
+    assert_eq_text!(ec.help().unwrap(), "reset the password of '***'");
+    assert!(matches!(
+        ec.error(),
+        x::Error::InvalidPassword { email } if email == "root@localhost"
+    ));
+    assert_eq_text!(
+        ec.trace().to_string(),
+        "
+Error: errore::x::InvalidPassword
+╰─▶ <errore::x::InvalidPassword> Invalid password for '***'
+    = help: reset the password of '***'
+    ╰╴ tests/test_redact.rs:43:9"
+    );
+    assert!(!ec.to_string().contains("root@localhost"));
+    assert!(!ec.trace().last().to_string().contains("root@localhost"));
+
+    let ec = x::Ec::new(x::Error::InvalidToken("secret".into(), 7));
+    assert_eq_text!(ec.error().to_string(), "Invalid token *** (7)");
+
+    let ec = x::Ec::new(x::Error::UnknownUser {
+        email: "root@localhost".into(),
+        name: "root".into(),
+    });
+    assert_eq_text!(ec.error().to_string(), "Unknown user '***' (***, ***, 14)");
+
+    let ec = x::KeyEc::new(x::KeyError("secret".into(), "pin".into()));
+    assert_eq_text!(ec.error().to_string(), "Locked key *** (***, 6)");
+
+    let ec = x::SessionEc::new(x::SessionError {
+        id: 3,
+        user: "root".into(),
+    });
+    assert_eq_text!(ec.error().to_string(), "Session 3 of '***' expired");
+    assert_eq!(ec.error().user, "root");
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "ctor")]
+
+use std::collections::HashSet;
+
+use errore::prelude::*;
+use errore::registry;
+use test_utils::*;
+
+pub mod x {
+    use super::*;
+
+    pub mod a {
+        use super::*;
+
+        #[derive(Error, Debug)]
+        #[errore(severity = "warn")]
+        pub enum Error {
+            /// The requested file was not found.
+            ///
+            /// Check the path.
+            #[error("File '{0}' not found")]
+            #[errore(code = "E-A01")]
+            NotFound(String),
+            #[error("Permission denied for {user}")]
+            #[errore(severity = "fatal")]
+            PermissionDenied { user: String },
+        }
+    }
+
+    pub mod b {
+        use super::*;
+
+        /// Failed to load the configuration.
+        #[derive(Error, Debug)]
+        #[error(transparent)]
+        pub struct Error(#[from] Box<a::Ec>);
+    }
+}
+
+#[test]
+fn test_registry() {
+    let descriptor = registry::iter()
+        .find(|d| d.name() == "errore::a::NotFound")
+        .unwrap();
+    assert_eq!(
+        descriptor.id(),
+        x::a::Ec::new(x::a::Error::NotFound("".into())).id()
+    );
+    assert_eq_text!(descriptor.target(), "test_registry");
+    assert_eq_text!(descriptor.display().unwrap(), "File '{0}' not found");
+    assert_eq_text!(
+        descriptor.doc(),
+        "
+The requested file was not found.
+
+Check the path."
+    );
+    assert!(descriptor.from().is_none());
+    assert!(!descriptor.is_transparent());
+    assert_eq!(descriptor.code(), Some("E-A01"));
+    assert_eq!(descriptor.severity(), Severity::Warn);
+
+    let descriptor = registry::iter()
+        .find(|d| d.name() == "errore::a::PermissionDenied")
+        .unwrap();
+    assert_eq_text!(
+        descriptor.display().unwrap(),
+        "Permission denied for {user}"
+    );
+    assert_eq_text!(descriptor.doc(), "");
+    assert_eq!(descriptor.code(), None);
+    assert_eq!(descriptor.severity(), Severity::Fatal);
+
+    let descriptor = registry::iter()
+        .find(|d| d.name() == "errore::b::Error")
+        .unwrap();
+    assert!(descriptor.display().is_none());
+    assert_eq_text!(descriptor.doc(), "Failed to load the configuration.");
+    assert_eq!(descriptor.from(), Some("Box<a::Ec>"));
+    assert!(descriptor.is_transparent());
+    assert_eq!(descriptor.severity(), Severity::Error);
+
+    let found = registry::get(descriptor.id()).unwrap();
+    assert_eq_text!(found.name(), "errore::b::Error");
+    assert_eq!(found.target_id(), descriptor.target_id());
+}
+
+#[test]
+fn test_registry_unique_id() {
+    let mut ids = HashSet::new();
+    for descriptor in registry::iter() {
+        assert!(
+            ids.insert(*descriptor.id()),
+            "duplicate id for {}",
+            descriptor.name()
+        );
+    }
+    assert_eq!(ids.len(), 3);
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "serde")]
+#![allow(clippy::result_large_err)]
+
+use errore::prelude::*;
+use test_utils::*;
+
+pub mod callee {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("Account '{user}' is locked")]
+        #[errore(code = "E-LOCK", help = "contact an admin")]
+        Locked { user: String, attempts: u32 },
+    }
+
+    pub fn authenticate() -> Result<(), Ec> {
+        err!(Error::Locked {
+            user: "root".into(),
+            attempts: 3
+        })
+    }
+}
+
+pub mod caller {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error(transparent)]
+        Remote(#[from] RemoteError),
+    }
+
+    pub fn request(response: &str) -> Result<(), Ec> {
+        let remote: RemoteError = serde_json::from_str(response).unwrap();
+        core::result::Result::Err(remote)?;
+        Ok(())
+    }
+}
+
+fn response() -> String {
+    let ec = callee::authenticate().unwrap_err();
+    serde_json::to_string(ec.trace()).unwrap()
+}
+
+#[test]
+fn test_remote() {
+    let ec = caller::request(&response()).unwrap_err();
+    assert_eq!(ec.trace().len(), 2);
+    assert_eq_text!(ec.error().to_string(), "Account 'root' is locked");
+    assert_eq_text!(
+        ec.trace().to_string(),
+        "
+Error: errore::caller::Remote
+├─▶ <errore::callee::Locked> [E-LOCK] Account 'root' is locked
+│   = help: contact an admin
+│   ╰╴ tests/test_remote.rs:18:9
+│
+╰─▶ <errore::caller::Remote>
+    ╰╴ tests/test_remote.rs:36:9"
+    );
+
+    let first = ec.trace().first();
+    assert_eq_text!(&first.target, "test_remote");
+    assert_eq!(first.code.as_deref(), Some("E-LOCK"));
+    assert_eq!(first.location.line(), 18);
+    assert_eq_text!(
+        first.error_ref().unwrap().to_string(),
+        "Account 'root' is locked"
+    );
+    assert!(ec.is_remote());
+}
+
+#[test]
+fn test_remote_roundtrip() {
+    let response = response();
+    let ec = caller::request(&response).unwrap_err();
+    let value = serde_json::to_value(ec.trace()).unwrap();
+    let expected: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(value["records"][0], expected["records"][0]);
+    assert_eq!(value["records"][0]["fields"]["attempts"], 3);
+}
+
+#[test]
+fn test_remote_invalid() {
+    assert!(serde_json::from_str::<RemoteError>(r#"{"records":[]}"#).is_err());
+    assert!(serde_json::from_str::<RemoteError>(r#"{"trace":"empty"}"#).is_err());
+}
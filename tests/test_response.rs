@@ -0,0 +1,74 @@
+#![cfg(any(feature = "axum", feature = "actix-web"))]
+
+use errore::prelude::*;
+
+pub mod auth {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("Account '{0}' is locked")]
+        #[errore(status = 403)]
+        Locked(String),
+        #[error("Database unavailable")]
+        Unavailable,
+    }
+}
+
+pub mod api {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error(transparent)]
+        Auth(#[from] auth::Ec),
+    }
+
+    pub fn login(error: auth::Error) -> Result<(), Ec> {
+        core::result::Result::Err(auth::Ec::new(error))?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn test_axum_response() {
+    use axum::response::IntoResponse;
+    use std::future::Future;
+    use std::task::{Context, Poll, Waker};
+
+    fn body(response: axum::response::Response) -> String {
+        let future = std::pin::pin!(axum::body::to_bytes(response.into_body(), usize::MAX));
+        match future.poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(bytes) => String::from_utf8(bytes.unwrap().to_vec()).unwrap(),
+            Poll::Pending => unreachable!(),
+        }
+    }
+
+    let ec = api::login(auth::Error::Locked("root".into())).unwrap_err();
+    let response = ec.into_response();
+    assert_eq!(response.status(), 403);
+    assert_eq!(body(response), "Account 'root' is locked");
+
+    let ec = api::login(auth::Error::Unavailable).unwrap_err();
+    let response = ec.into_response();
+    assert_eq!(response.status(), 500);
+    assert_eq!(body(response), "Database unavailable");
+}
+
+#[cfg(feature = "actix-web")]
+#[test]
+fn test_actix_response() {
+    use actix_web::body::MessageBody;
+    use actix_web::ResponseError;
+
+    let ec = api::login(auth::Error::Locked("root".into())).unwrap_err();
+    assert_eq!(ec.status_code(), 403);
+    let body = ec.error_response().into_body().try_into_bytes().unwrap();
+    assert_eq!(body, "Account 'root' is locked");
+
+    let ec = auth::Ec::new(auth::Error::Unavailable);
+    assert_eq!(ec.status_code(), 500);
+    let body = ec.error_response().into_body().try_into_bytes().unwrap();
+    assert_eq!(body, "Database unavailable");
+}
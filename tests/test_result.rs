@@ -9,7 +9,7 @@ fn test_from_residual_core_result_for_result() {
     pub struct Error(#[from] std::io::Error);
 
     fn core_result() -> core::result::Result<(), std::io::Error> {
-        core::result::Result::Err(std::io::Error::new(std::io::ErrorKind::Other, "..."))
+        core::result::Result::Err(std::io::Error::other("..."))
     }
 
     fn result() -> Result<(), Ec> {
@@ -80,6 +80,7 @@ fn test_from_residual_never_result_for_core_result() {
         Err(Ec::new(Error::Field))
     }
 
+    #[allow(clippy::result_large_err)]
     pub fn core_result() -> core::result::Result<(), Ec> {
         result()?;
         core::result::Result::Ok(())
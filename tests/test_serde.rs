@@ -0,0 +1,115 @@
+#![cfg(feature = "serde")]
+
+use errore::prelude::*;
+use serde_json::json;
+
+pub mod x {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("Invalid token for '{user}' ({attempts} attempts)")]
+        #[errore(code = "E-AUTH")]
+        InvalidToken {
+            #[redact]
+            token: String,
+            user: String,
+            attempts: u32,
+        },
+    }
+
+    pub fn verify() -> Result<(), Ec> {
+        err!(Error::InvalidToken {
+            token: "secret".into(),
+            user: "root".into(),
+            attempts: 3,
+        })
+    }
+}
+
+pub mod y {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error(transparent)]
+        Auth(#[from] x::Ec),
+    }
+
+    pub fn login() -> Result<(), Ec> {
+        x::verify()?;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_serialize_trace() {
+    let ec = y::login().unwrap_err();
+    let value = serde_json::to_value(ec.trace()).unwrap();
+    let first = ec.trace().first();
+    assert_eq!(
+        value["records"][0],
+        json!({
+            "name": "errore::x::InvalidToken",
+            "id": *first.id,
+            "target": "test_serde",
+            "target_id": *first.target_id,
+            "location": {
+                "file": "tests/test_serde.rs",
+                "line": 22,
+                "column": 9
+            },
+            "is_transparent": false,
+            "code": "E-AUTH",
+            "severity": "error",
+            "classification": "unclassified",
+            "status": null,
+            "grpc_code": null,
+            "message": "Invalid token for 'root' (3 attempts)",
+            "context": null,
+            "help": null,
+            "note": null,
+            "fields": {
+                "token": "***",
+                "user": "root",
+                "attempts": 3
+            },
+            "nested": []
+        })
+    );
+
+    let last = &value["records"][1];
+    assert_eq!(value["records"].as_array().unwrap().len(), 2);
+    assert_eq!(last["name"], "errore::y::Auth");
+    assert_eq!(last["is_transparent"], true);
+    assert_eq!(last["code"], json!(null));
+    assert_eq!(last["location"]["line"], 40);
+    assert_eq!(last["message"], "Invalid token for 'root' (3 attempts)");
+    assert!(last["fields"]["0"]
+        .as_str()
+        .unwrap()
+        .starts_with("Ec(Span {"));
+}
+
+#[test]
+fn test_serialize_dropped() {
+    let record = x::verify().unwrap_err().trace().first().clone();
+    let value = serde_json::to_value(&record).unwrap();
+    assert_eq!(value["name"], "errore::x::InvalidToken");
+    assert_eq!(value["message"], json!(null));
+    assert_eq!(value["fields"], json!(null));
+}
+
+#[test]
+fn test_serialize_location() {
+    let ec = x::verify().unwrap_err();
+    let record = ec.trace().first();
+    assert_eq!(
+        serde_json::to_string(&record.location).unwrap(),
+        r#"{"file":"tests/test_serde.rs","line":22,"column":9}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&record.id).unwrap(),
+        record.id.to_string()
+    );
+}
@@ -11,7 +11,7 @@ fn test_implicit_source() {
         source: io::Error,
     }
 
-    let io = io::Error::new(io::ErrorKind::Other, "oh no!");
+    let io = io::Error::other("oh no!");
     let error = ImplicitSource { source: io };
     error.source().unwrap().downcast_ref::<io::Error>().unwrap();
 }
@@ -26,7 +26,7 @@ fn test_explicit_source() {
         io: io::Error,
     }
 
-    let io = io::Error::new(io::ErrorKind::Other, "oh no!");
+    let io = io::Error::other("oh no!");
     let error = ExplicitSource {
         source: String::new(),
         io,
@@ -43,7 +43,7 @@ fn test_boxed_source() {
         source: Box<dyn StdError + Send + 'static + Send + Sync>,
     }
 
-    let source = Box::new(io::Error::new(io::ErrorKind::Other, "oh no!"));
+    let source = Box::new(io::Error::other("oh no!"));
     let error = BoxedSource { source };
     error.source().unwrap().downcast_ref::<io::Error>().unwrap();
 }
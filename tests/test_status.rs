@@ -0,0 +1,75 @@
+#![allow(clippy::large_enum_variant)]
+
+use errore::prelude::*;
+
+pub mod auth {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    #[errore(status = 403)]
+    pub enum Error {
+        #[error("Account is locked")]
+        Locked,
+        #[error("Token expired")]
+        #[errore(status = 401)]
+        Expired,
+    }
+}
+
+pub mod api {
+    use super::*;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error(transparent)]
+        Auth(#[from] auth::Ec),
+        #[error("Request failed")]
+        #[errore(status = 502)]
+        Failed(#[from] auth::Error),
+        #[error("Internal error")]
+        Internal,
+    }
+
+    pub fn login(error: auth::Error) -> Result<(), Ec> {
+        core::result::Result::Err(auth::Ec::new(error))?;
+        Ok(())
+    }
+
+    pub fn request(error: auth::Error) -> Result<(), Ec> {
+        core::result::Result::Err(error)?;
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug)]
+#[errore(context = NotFoundEc)]
+#[error("Not found")]
+#[errore(status = 404)]
+pub struct NotFound;
+
+#[test]
+fn test_status() {
+    assert_eq!(auth::Error::Locked.status(), Some(403));
+    assert_eq!(auth::Error::Expired.status(), Some(401));
+    assert_eq!(api::Error::Internal.status(), None);
+
+    let ec = NotFoundEc::new(NotFound);
+    assert_eq!(ec.status(), Some(404));
+    assert_eq!(ec.trace().last().status(), Some(404));
+    assert_eq!(ec.trace().status(), Some(404));
+}
+
+#[test]
+fn test_status_resolve() {
+    // Transparent records are skipped.
+    let ec = api::login(auth::Error::Expired).unwrap_err();
+    assert_eq!(ec.status(), None);
+    assert_eq!(ec.trace().status(), Some(401));
+
+    // The outermost record wins.
+    let ec = api::request(auth::Error::Locked).unwrap_err();
+    assert_eq!(ec.trace().status(), Some(502));
+
+    let ec = api::Ec::internal();
+    assert_eq!(ec.trace().status(), None);
+}
@@ -0,0 +1,87 @@
+use errore::prelude::*;
+
+pub mod x {
+    use super::*;
+
+    pub mod a {
+        use super::*;
+
+        #[derive(Error, Debug)]
+        #[error("Failed to send {} bytes", buffer.len())]
+        pub struct Error {
+            pub buffer: Vec<u8>,
+        }
+
+        pub fn send(buffer: Vec<u8>) -> Result<(), Ec> {
+            err!(Error { buffer })
+        }
+    }
+
+    pub mod b {
+        use super::*;
+
+        #[derive(Error, Debug)]
+        pub enum Error {
+            #[error(transparent)]
+            Send(#[from] a::Ec),
+        }
+
+        pub fn upload(buffer: Vec<u8>) -> Result<(), Ec> {
+            a::send(buffer)?;
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn test_into_parts() {
+    let ec = x::a::send(vec![1, 2, 3]).unwrap_err();
+    let (error, trace) = ec.into_parts().unwrap();
+    assert_eq!(error.buffer, vec![1, 2, 3]);
+    assert_eq!(trace.len(), 1);
+    assert_eq!(trace.first().name, "errore::a::Error");
+    // The record doesn't refer to the moved error anymore.
+    assert!(trace.first().error_ref().is_none());
+
+    let ec = x::a::send(vec![4]).unwrap_err();
+    assert_eq!(ec.into_inner().unwrap().buffer, vec![4]);
+}
+
+#[test]
+fn test_into_parts_shared() {
+    let ec = x::a::send(vec![1, 2, 3]).unwrap_err();
+    let shared = ec.get::<x::a::Error>().unwrap().into_arc();
+
+    let ec = ec.into_parts().unwrap_err();
+    assert_eq!(ec.error().buffer, vec![1, 2, 3]);
+    assert_eq!(ec.trace().len(), 1);
+
+    drop(shared);
+    assert_eq!(ec.into_inner().unwrap().buffer, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_take() {
+    let ec = x::b::upload(vec![1, 2, 3]).unwrap_err();
+    let error = ec.take::<x::a::Error>().unwrap().unwrap();
+    assert_eq!(error.buffer, vec![1, 2, 3]);
+
+    let ec = x::b::upload(vec![1, 2, 3]).unwrap_err();
+    assert!(ec.take::<x::b::Error>().unwrap().is_ok());
+
+    let ec = x::a::send(vec![1, 2, 3]).unwrap_err();
+    assert!(ec.take::<x::b::Error>().is_none());
+}
+
+#[test]
+fn test_take_shared() {
+    let ec = x::b::upload(vec![1, 2, 3]).unwrap_err();
+    let shared = ec.get::<x::a::Error>().unwrap().into_arc();
+
+    let downcasted = ec.take::<x::a::Error>().unwrap().unwrap_err();
+    assert_eq!(downcasted.buffer, vec![1, 2, 3]);
+    let downcasted = downcasted.into_inner().unwrap_err();
+
+    drop(shared);
+    assert_eq!(downcasted.into_inner().unwrap().buffer, vec![1, 2, 3]);
+}
@@ -9,7 +9,7 @@ fn test_trace_from_error() {
         Field(#[from] std::io::Error),
     }
 
-    let error = std::io::Error::new(std::io::ErrorKind::Other, "");
+    let error = std::io::Error::other("");
     let ec = Ec::from(error);
     let trace = ec.trace();
 
@@ -20,6 +20,7 @@ fn test_trace_from_error() {
 }
 
 #[test]
+#[cfg(feature = "nightly")]
 fn test_trace_from_ec() {
     pub mod x {
         use super::*;
@@ -50,7 +51,7 @@ fn test_trace_from_ec() {
 
     assert_eq!(trace.len(), 1);
     assert_eq_text!(trace.last().location.file(), "tests/test_trace_enum.rs");
-    assert_eq!(trace.last().location.line(), 48);
+    assert_eq!(trace.last().location.line(), 49);
 
     // trace record is only appended with 'Result'
     let ec = x::b::Ec::from(ec);
@@ -59,6 +60,7 @@ fn test_trace_from_ec() {
 }
 
 #[test]
+#[cfg(feature = "nightly")]
 fn test_trace_result() {
     pub mod x {
         use super::*;
@@ -1,3 +1,5 @@
+#![cfg(feature = "nightly")]
+
 use std::path::PathBuf;
 use std::sync::Once;
 
@@ -121,7 +123,7 @@ pub fn init_subscriber() {
     });
 }
 
-fn init<'a>(on: usize, cb: &dyn Fn(&TraceContext)) -> (String, String) {
+fn init(on: usize, cb: &dyn Fn(&TraceContext)) -> (String, String) {
     init_subscriber();
     let ec_str;
     let ec_trace_str;
@@ -132,10 +134,10 @@ fn init<'a>(on: usize, cb: &dyn Fn(&TraceContext)) -> (String, String) {
         let trace = ec.trace();
         ec_str = ec.to_string();
         ec_trace_str = trace.to_string();
-        cb(&trace);
+        cb(trace);
     }
 
-    return (ec_str, ec_trace_str);
+    (ec_str, ec_trace_str)
 }
 
 #[test]
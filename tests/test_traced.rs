@@ -0,0 +1,100 @@
+#![allow(clippy::result_large_err)]
+
+use core::result::Result::{self, Err, Ok};
+
+use errore::prelude::*;
+use test_utils::*;
+
+pub mod x {
+    use super::*;
+
+    pub mod a {
+        use super::*;
+
+        #[derive(Error, Debug)]
+        pub enum Error {
+            #[error("Invalid token")]
+            InvalidToken,
+        }
+
+        pub fn verify() -> Result<(), Ec> {
+            err!(Error::InvalidToken)
+        }
+
+        pub fn authenticate() -> Result<(), Ec> {
+            verify().traced()?;
+            Ok(())
+        }
+    }
+
+    pub mod b {
+        use super::*;
+
+        #[derive(Error, Debug)]
+        pub enum Error {
+            #[error(transparent)]
+            Auth(#[from] a::Ec),
+        }
+
+        pub fn login() -> Result<(), Ec> {
+            a::authenticate()?;
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn test_traced() {
+    let ec = x::a::authenticate().unwrap_err();
+    assert_eq!(ec.trace().len(), 2);
+    assert_eq!(ec.trace().first().location.line(), 21);
+    assert_eq!(ec.trace().last().location.line(), 25);
+    assert_eq_text!(&ec.trace().last().name, "errore::a::InvalidToken");
+
+    let ok: Result<u32, x::a::Ec> = Ok(1);
+    assert_eq!(ok.traced().unwrap(), 1);
+}
+
+#[cfg(not(feature = "nightly"))]
+#[test]
+fn test_traced_from() {
+    let ec = x::b::login().unwrap_err();
+    assert_eq_text!(
+        ec.trace().to_string(),
+        "
+Error: errore::b::Auth
+├─▶ <errore::a::InvalidToken> Invalid token
+│   ├╴ tests/test_traced.rs:21:13
+│   ╰╴ tests/test_traced.rs:25:22
+│
+╰─▶ <errore::b::Auth>
+    ╰╴ tests/test_traced.rs:40:13"
+    );
+}
+
+#[test]
+fn test_traced_context() {
+    fn load(path: &str) -> Result<(), x::a::Ec> {
+        x::a::verify()
+            .context("verifying the token")
+            .with_context(|| format!("loading {}", path))
+            .attach(path.to_string())
+            .attach_with(|| 3u32)
+    }
+
+    let ec = load("users.toml").unwrap_err();
+    let contexts = ec.trace().iter().filter_map(|rec| rec.context());
+    assert_eq!(
+        contexts.collect::<Vec<_>>(),
+        ["verifying the token", "loading users.toml"]
+    );
+    assert_eq!(ec.trace().last().location.line(), 80);
+    assert_eq!(
+        ec.attachments::<String>().collect::<Vec<_>>(),
+        ["users.toml"]
+    );
+    assert_eq!(ec.trace().last().attachments::<u32>().count(), 1);
+
+    let ok: Result<u32, x::a::Ec> = Ok(1);
+    assert_eq!(ok.context("unused").attach(2u8).unwrap(), 1);
+}
@@ -34,7 +34,7 @@ errore::test_transparent::Error: E0
         ec.to_string()
     );
 
-    let io = io::Error::new(io::ErrorKind::Other, "oh no!");
+    let io = io::Error::other("oh no!");
     let error = Error(a::ErrorKind::from(io));
     assert_eq!("E1", error.to_string());
     error.source().unwrap().downcast_ref::<io::Error>().unwrap();
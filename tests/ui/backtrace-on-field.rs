@@ -0,0 +1,10 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+#[error("...")]
+pub struct ErrorStruct {
+    #[backtrace]
+    pub path: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+pub enum ErrorEnum {
+    #[error("...")]
+    #[errore(context = VariantEc)]
+    Variant,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+#[error("...")]
+#[errore(transient, permanent)]
+pub struct ErrorStruct;
+
+fn main() {}
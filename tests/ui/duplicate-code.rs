@@ -0,0 +1,13 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+pub enum ErrorEnum {
+    #[error("first")]
+    #[errore(code = "E001")]
+    First,
+    #[error("second")]
+    #[errore(code = "E001")]
+    Second,
+}
+
+fn main() {}
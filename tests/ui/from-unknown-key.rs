@@ -0,0 +1,7 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+#[error("{0}")]
+pub struct ErrorStruct(#[from(via = &'static str, wth = str::to_owned)] String);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+#[error("...")]
+pub struct ErrorStruct {
+    #[errore(help = "try again")]
+    pub retries: u32,
+}
+
+fn main() {}
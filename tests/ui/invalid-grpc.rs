@@ -0,0 +1,8 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+#[error("...")]
+#[errore(grpc = "MISSING")]
+pub struct Error;
+
+fn main() {}
@@ -0,0 +1,8 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+#[error("...")]
+#[errore(severity = "critical")]
+pub struct ErrorStruct;
+
+fn main() {}
@@ -0,0 +1,8 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+#[error("...")]
+#[errore(status = 42)]
+pub struct Error;
+
+fn main() {}
@@ -0,0 +1,8 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+#[errore(kind = StructKind)]
+#[error("...")]
+pub struct ErrorStruct;
+
+fn main() {}
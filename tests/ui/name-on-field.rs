@@ -0,0 +1,10 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+#[error("...")]
+pub struct ErrorStruct {
+    #[errore(name = "field")]
+    field: u32,
+}
+
+fn main() {}
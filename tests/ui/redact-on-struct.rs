@@ -0,0 +1,10 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+#[redact]
+#[error("...")]
+pub struct ErrorStruct {
+    field: u32,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use errore::prelude::*;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Invalid code")]
+    Code,
+    #[error("Unexpected status {0}")]
+    Status(u16),
+}
+
+fn main() {
+    // predicates are still generated
+    let ec = Ec::new(Error::Status(500));
+    assert!(ec.is_status());
+    assert!(!ec.is_code());
+
+    // constructors don't shadow the methods of errore::Metadata
+    let _ = Ec::code();
+    let _ = Ec::status(404);
+}
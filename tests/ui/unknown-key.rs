@@ -0,0 +1,8 @@
+use errore::*;
+
+#[derive(Error, Debug)]
+#[error("...")]
+#[errore(cod = "E001")]
+pub struct ErrorStruct;
+
+fn main() {}